@@ -6,7 +6,7 @@ use std::sync::Arc;
 use vrp_core::models::{Problem as CoreProblem, Solution as CoreSolution};
 use vrp_core::solver::Builder;
 use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem, Problem};
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Matrix, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::{deserialize_solution, PragmaticSolution, Solution};
 
 fn main() {
@@ -37,7 +37,7 @@ fn run_examples(base_path: &str) {
         let problem = BufReader::new(open_file(format!["{}/{}.problem.json", base_path, name].as_str()));
 
         let problem = Arc::new(
-            if let Some(matrices) = matrices {
+            if let Some(matrices) = matrices.as_ref() {
                 let matrices = matrices
                     .iter()
                     .map(|path| BufReader::new(open_file(format!["{}/{}.json", base_path, path].as_str())))
@@ -64,9 +64,10 @@ fn run_examples(base_path: &str) {
 
         let solution = get_pragmatic_solution(&Arc::try_unwrap(problem).ok().unwrap(), &solution);
         let problem = get_pragmatic_problem(base_path, name);
+        let matrices = matrices
+            .map(|matrices| matrices.iter().map(|path| get_pragmatic_matrix(base_path, path)).collect::<Vec<_>>());
 
-        // TODO use matrices
-        if let Err(err) = CheckerContext::new(problem, None, solution).check() {
+        if let Err(err) = CheckerContext::new(problem, matrices, solution).check() {
             panic!("unfeasible solution in '{}': '{}'", name, err);
         }
     }
@@ -81,6 +82,10 @@ fn get_pragmatic_problem(base_path: &str, name: &str) -> Problem {
     deserialize_problem(BufReader::new(open_file(format!["{}/{}.problem.json", base_path, name].as_str()))).unwrap()
 }
 
+fn get_pragmatic_matrix(base_path: &str, path: &str) -> Matrix {
+    deserialize_matrix(BufReader::new(open_file(format!["{}/{}.json", base_path, path].as_str()))).unwrap()
+}
+
 fn get_pragmatic_solution(problem: &CoreProblem, solution: &CoreSolution) -> Solution {
     let mut buffer = String::new();
     let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
@@ -1,13 +1,15 @@
 use super::*;
-use std::io::BufReader;
+use std::io::{BufReader, BufWriter};
 use std::process;
-use vrp_pragmatic::checker::CheckerContext;
-use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::checker::{serialize_report, CheckerContext};
+use vrp_pragmatic::format::problem::{deserialize_matrix_with_format, deserialize_problem, MatrixFormat};
 use vrp_pragmatic::format::solution::deserialize_solution;
 
 pub const FORMAT_ARG_NAME: &str = "FORMAT";
 pub const PROBLEM_ARG_NAME: &str = "problem-files";
 pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const MATRIX_FORMAT_ARG_NAME: &str = "matrix-format";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
 
 pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
     App::new("check")
@@ -21,7 +23,7 @@ pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
         )
         .arg(
             Arg::with_name(PROBLEM_ARG_NAME)
-                .help("Sets input files which contain a VRP definition")
+                .help("Sets input files which contain a VRP definition and optional routing matrices")
                 .short("p")
                 .long(PROBLEM_ARG_NAME)
                 .required(true)
@@ -36,33 +38,80 @@ pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
                 .required(true)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(MATRIX_FORMAT_ARG_NAME)
+                .help("Specifies routing matrix format, it is detected by file extension if omitted")
+                .long(MATRIX_FORMAT_ARG_NAME)
+                .possible_values(&["json", "binary", "csv"])
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for check report output in json format")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 pub fn run_check(matches: &ArgMatches) {
     let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
-    let problem_files = matches
-        .values_of(PROBLEM_ARG_NAME)
-        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "problem"))).collect::<Vec<_>>());
+    let matrix_format = matches.value_of(MATRIX_FORMAT_ARG_NAME).map(|format| format.parse::<MatrixFormat>().unwrap());
+    let problem_files = matches.values_of(PROBLEM_ARG_NAME).map(|paths: Values| paths.collect::<Vec<_>>());
     let solution_file = matches.value_of(SOLUTION_ARG_NAME).map(|path| BufReader::new(open_file(path, "solution")));
+    let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out report"));
 
     let result = match (input_format, problem_files, solution_file) {
-        ("pragmatic", Some(mut problem_files), Some(solution_file)) if problem_files.len() == 1 => {
-            // TODO support matrix
-            let problem_file = problem_files.swap_remove(0);
+        ("pragmatic", Some(mut problem_files), Some(solution_file)) if !problem_files.is_empty() => {
+            let problem_file = BufReader::new(open_file(problem_files.remove(0), "problem"));
+            let matrices = if problem_files.is_empty() {
+                Ok(None)
+            } else {
+                problem_files
+                    .into_iter()
+                    .map(|path| {
+                        let file = BufReader::new(open_file(path, "routing matrix"));
+                        let format = matrix_format.unwrap_or_else(|| MatrixFormat::from_path(path));
+                        deserialize_matrix_with_format(file, format)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Some)
+            };
 
-            deserialize_problem(problem_file)
-                .into_iter()
-                .zip(deserialize_solution(solution_file).into_iter())
-                .map(|(problem, solution)| CheckerContext::new(problem, None, solution).check())
-                .next()
-                .expect("Cannot deserialize problem or solution")
+            matrices
+                .and_then(|matrices| deserialize_problem(problem_file).map(|problem| (problem, matrices)))
+                .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+                .and_then(|(problem, matrices)| {
+                    deserialize_solution(solution_file)
+                        .map(|solution| CheckerContext::new(problem, matrices, solution).create_report())
+                        .map_err(|err| format!("cannot deserialize solution: '{}'", err))
+                })
+        }
+        ("pragmatic", _, _) => {
+            Err("pragmatic format expects one problem, optional matrices and one solution file".to_string())
         }
-        ("pragmatic", _, _) => Err("pragmatic format expects one problem and one solution file".to_string()),
         _ => Err(format!("unknown format: '{}'", input_format)),
     };
 
-    if let Err(err) = result {
-        eprintln!("{}", err);
-        process::exit(1);
+    match result {
+        Ok(report) => {
+            if let Some(out_result) = out_result {
+                serialize_report(BufWriter::new(out_result), &report).expect("Cannot write check report");
+            }
+
+            report.violations.iter().for_each(|violation| {
+                eprintln!("{} ({:?}): {}", violation.code, violation.severity, violation.message)
+            });
+
+            if !report.is_feasible() {
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
     }
 }
@@ -0,0 +1,96 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_pragmatic::diff::{diff_solutions, serialize_diff, serialize_diff_as_text};
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const OLD_SOLUTION_ARG_NAME: &str = "old-solution";
+pub const NEW_SOLUTION_ARG_NAME: &str = "new-solution";
+pub const THRESHOLD_ARG_NAME: &str = "threshold";
+pub const OUT_FORMAT_ARG_NAME: &str = "out-format";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_diff_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("diff")
+        .about("Provides the way to compare two solutions of the same problem")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(OLD_SOLUTION_ARG_NAME)
+                .help("Sets old solution file")
+                .long(OLD_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(NEW_SOLUTION_ARG_NAME)
+                .help("Sets new solution file")
+                .long(NEW_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(THRESHOLD_ARG_NAME)
+                .help("Specifies arrival time shift threshold in seconds: smaller shifts are not reported")
+                .short("t")
+                .long(THRESHOLD_ARG_NAME)
+                .required(false)
+                .default_value("0")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies output format of the diff")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["text", "json"])
+                .default_value("text")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for diff output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_diff(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let old_solution = BufReader::new(open_file(matches.value_of(OLD_SOLUTION_ARG_NAME).unwrap(), "old solution"));
+    let new_solution = BufReader::new(open_file(matches.value_of(NEW_SOLUTION_ARG_NAME).unwrap(), "new solution"));
+    let threshold = matches.value_of(THRESHOLD_ARG_NAME).unwrap().parse::<i64>().unwrap_or_else(|err| {
+        eprintln!("Cannot get threshold: '{}'", err.to_string());
+        process::exit(1);
+    });
+    let out_format = matches.value_of(OUT_FORMAT_ARG_NAME).unwrap();
+    let out_buffer =
+        create_write_buffer(matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out diff")));
+
+    let result = match input_format {
+        "pragmatic" => deserialize_solution(old_solution)
+            .and_then(|old| deserialize_solution(new_solution).map(|new| (old, new)))
+            .map_err(|err| format!("cannot deserialize solution: '{}'", err))
+            .and_then(|(old, new)| {
+                let diff = diff_solutions(&old, &new, threshold);
+                match out_format {
+                    "json" => serialize_diff(out_buffer, &diff).map_err(|err| err.to_string()),
+                    _ => serialize_diff_as_text(out_buffer, &diff).map_err(|err| err.to_string()),
+                }
+            }),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_pragmatic::format::problem::{deserialize_problem, serialize_problem, RelationType};
+use vrp_pragmatic::format::solution::{create_relations, deserialize_solution, merge_relations};
+
+pub const FORMAT_ARG_NAME: &str = "FORMAT";
+pub const PROBLEM_ARG_NAME: &str = "problem-file";
+pub const SOLUTION_ARG_NAME: &str = "solution-file";
+pub const RELATION_TYPE_ARG_NAME: &str = "relation-type";
+pub const VEHICLE_IDS_ARG_NAME: &str = "vehicle-ids";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_freeze_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("freeze")
+        .about("Provides the way to lock tours of the solution by adding them as relations to the problem")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(RELATION_TYPE_ARG_NAME)
+                .help("Specifies type of created relations, strict one is downgraded to sequence when tour items are skipped")
+                .short("t")
+                .long(RELATION_TYPE_ARG_NAME)
+                .required(false)
+                .possible_values(&["sequence", "strict"])
+                .default_value("strict")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(VEHICLE_IDS_ARG_NAME)
+                .help("Specifies ids of vehicles which tours should be locked, all tours are locked if omitted")
+                .short("v")
+                .long(VEHICLE_IDS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .multiple(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result problem output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_freeze(matches: &ArgMatches) {
+    let input_format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let problem_file = BufReader::new(open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_file = BufReader::new(open_file(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let relation_type = match matches.value_of(RELATION_TYPE_ARG_NAME).unwrap() {
+        "sequence" => RelationType::Sequence,
+        _ => RelationType::Strict,
+    };
+    let vehicle_ids =
+        matches.values_of(VEHICLE_IDS_ARG_NAME).map(|ids: Values| ids.map(|id| id.to_string()).collect::<Vec<_>>());
+
+    let result = match input_format {
+        "pragmatic" => deserialize_problem(problem_file)
+            .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+            .and_then(|problem| {
+                deserialize_solution(solution_file)
+                    .map(|solution| (problem, solution))
+                    .map_err(|err| format!("cannot deserialize solution: '{}'", err))
+            })
+            .map(|(problem, solution)| {
+                merge_relations(problem, create_relations(&solution, relation_type, vehicle_ids.as_deref()))
+            }),
+        _ => Err(format!("unknown format: '{}'", input_format)),
+    };
+
+    match result {
+        Ok(problem) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            if let Err(err) = serialize_problem(create_write_buffer(out_result), &problem) {
+                eprintln!("Cannot serialize result problem: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use clap::{App, Arg, ArgMatches, Values};
 
 pub mod check;
+pub mod diff;
+pub mod freeze;
 pub mod import;
 pub mod solve;
 
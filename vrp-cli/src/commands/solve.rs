@@ -7,9 +7,15 @@ use std::process;
 use std::sync::Arc;
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
 use vrp_core::models::{Problem, Solution};
-use vrp_core::solver::Builder;
-use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
-use vrp_pragmatic::format::solution::PragmaticSolution;
+use vrp_core::solver::{Builder, LowerBounds};
+use vrp_pragmatic::format::problem::{
+    deserialize_matrix_with_format, deserialize_problem, MatrixFormat, PragmaticProblem,
+};
+use vrp_pragmatic::format::solution::{
+    create_geojson_options, create_solution, serialize_solution, serialize_solution_as_csv,
+    serialize_solution_as_geojson_with_options, serialize_solution_as_html_with_options,
+};
+use vrp_pragmatic::validation::ValidationContext;
 use vrp_scientific::common::read_init_solution;
 use vrp_scientific::lilim::{LilimProblem, LilimSolution};
 use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
@@ -17,60 +23,79 @@ use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
 const MATRIX_ARG_NAME: &str = "matrix";
+const MATRIX_FORMAT_ARG_NAME: &str = "matrix-format";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const COST_VARIATION_ARG_NAME: &str = "cost-variation";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const GEO_JSON_PER_TOUR_ARG_NAME: &str = "geo-json-per-tour";
+const CSV_ARG_NAME: &str = "out-csv";
+const HTML_ARG_NAME: &str = "out-html";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
+const ANALYZE_ARG_NAME: &str = "analyze";
 
-struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
+struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<(File, MatrixFormat)>>) -> Result<Problem, String>>);
 
 struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Option<Solution>>);
 
 struct SolutionWriter(
     pub  Box<
-        dyn Fn(&Problem, Solution, BufWriter<Box<dyn Write>>, Option<BufWriter<Box<dyn Write>>>) -> Result<(), String>,
+        dyn Fn(
+            &Problem,
+            Solution,
+            BufWriter<Box<dyn Write>>,
+            Option<BufWriter<Box<dyn Write>>>,
+            Option<BufWriter<Box<dyn Write>>>,
+            Option<BufWriter<Box<dyn Write>>>,
+        ) -> Result<(), String>,
     >,
 );
 
 struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
-fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)> {
+fn get_formats<'a>(
+    is_geojson_per_tour: bool,
+) -> HashMap<&'a str, (ProblemReader, InitSolutionReader, SolutionWriter, LocationWriter)> {
     vec![
         (
             "solomon",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<(File, MatrixFormat)>>| {
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_solomon()
                 })),
                 InitSolutionReader(Box::new(|file, problem| read_init_solution(BufReader::new(file), problem).ok())),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_solomon(writer))),
+                SolutionWriter(Box::new(|_, solution, writer, _, _, _| solution.write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         ),
         (
             "lilim",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<(File, MatrixFormat)>>| {
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_lilim()
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|_, solution, writer, _| solution.write_lilim(writer))),
+                SolutionWriter(Box::new(|_, solution, writer, _, _, _| solution.write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         ),
         (
             "pragmatic",
             (
-                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<(File, MatrixFormat)>>| {
                     if let Some(matrices) = matrices {
-                        let matrices = matrices.into_iter().map(|m| BufReader::new(m)).collect();
-                        (BufReader::new(problem), matrices).read_pragmatic()
+                        deserialize_problem(BufReader::new(problem)).and_then(|problem| {
+                            matrices
+                                .into_iter()
+                                .map(|(matrix, format)| deserialize_matrix_with_format(BufReader::new(matrix), format))
+                                .collect::<Result<Vec<_>, _>>()
+                                .and_then(|matrices| (problem, matrices).read_pragmatic())
+                        })
                     } else {
                         println!("configured to use single approximated routing matrix");
                         BufReader::new(problem).read_pragmatic()
@@ -78,11 +103,34 @@ fn get_formats<'a>() -> HashMap<&'a str, (ProblemReader, InitSolutionReader, Sol
                     .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| None)),
-                SolutionWriter(Box::new(|problem, solution, default_writer, geojson_writer| {
-                    geojson_writer
-                        .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
-                        .and_then(|_| solution.write_pragmatic_json(problem, default_writer))
-                })),
+                SolutionWriter(Box::new(
+                    move |problem, solution, default_writer, geojson_writer, csv_writer, html_writer| {
+                        // NOTE solution is converted once as it is expensive to explain unassigned jobs
+                        let api_solution = create_solution(problem, &solution);
+
+                        geojson_writer
+                            .map_or(Ok(()), |geojson_writer| {
+                                let options = create_geojson_options(&solution, is_geojson_per_tour);
+                                serialize_solution_as_geojson_with_options(geojson_writer, &api_solution, &options)
+                                    .map_err(|err| err.to_string())
+                            })
+                            .and_then(|_| {
+                                csv_writer.map_or(Ok(()), |csv_writer| {
+                                    serialize_solution_as_csv(csv_writer, &api_solution).map_err(|err| err.to_string())
+                                })
+                            })
+                            .and_then(|_| {
+                                html_writer.map_or(Ok(()), |html_writer| {
+                                    let options = create_geojson_options(&solution, false);
+                                    serialize_solution_as_html_with_options(html_writer, &api_solution, &options)
+                                        .map_err(|err| err.to_string())
+                                })
+                            })
+                            .and_then(|_| {
+                                serialize_solution(default_writer, &api_solution).map_err(|err| err.to_string())
+                            })
+                    },
+                )),
                 LocationWriter(Box::new(|problem, writer| {
                     let mut writer = writer;
                     deserialize_problem(BufReader::new(problem))
@@ -149,6 +197,15 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(MATRIX_FORMAT_ARG_NAME)
+                .help("Specifies routing matrix format, it is detected by file extension if omitted")
+                .long(MATRIX_FORMAT_ARG_NAME)
+                .possible_values(&["json", "binary", "csv"])
+                .requires(MATRIX_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
@@ -172,11 +229,39 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(GEO_JSON_PER_TOUR_ARG_NAME)
+                .help("Writes geo json output as a list of feature collections, one per tour")
+                .long(GEO_JSON_PER_TOUR_ARG_NAME)
+                .requires(GEO_JSON_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::with_name(CSV_ARG_NAME)
+                .help("Specifies path to solution output in csv format")
+                .long(CSV_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(HTML_ARG_NAME)
+                .help("Specifies path to solution report in html format")
+                .long(HTML_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(ANALYZE_ARG_NAME)
+                .help("Analyzes pragmatic problem feasibility and reports warnings before solving")
+                .short("a")
+                .long(ANALYZE_ARG_NAME)
+                .required(false),
+        )
 }
 
 /// Runs solver commands.
 pub fn run_solve(matches: &ArgMatches) {
-    let formats = get_formats();
+    let formats = get_formats(matches.is_present(GEO_JSON_PER_TOUR_ARG_NAME));
 
     // required
     let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
@@ -207,17 +292,30 @@ pub fn run_solve(matches: &ArgMatches) {
         }
     });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
-    let matrix_files = matches
-        .values_of(MATRIX_ARG_NAME)
-        .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect());
+    let matrix_format = matches.value_of(MATRIX_FORMAT_ARG_NAME).map(|format| format.parse::<MatrixFormat>().unwrap());
+    let matrix_files = matches.values_of(MATRIX_ARG_NAME).map(|paths: Values| {
+        paths
+            .map(|path| {
+                (open_file(path, "routing matrix"), matrix_format.unwrap_or_else(|| MatrixFormat::from_path(path)))
+            })
+            .collect()
+    });
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_csv = matches.value_of(CSV_ARG_NAME).map(|path| create_file(path, "out csv"));
+    let out_html = matches.value_of(HTML_ARG_NAME).map(|path| create_file(path, "out html"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
 
+    if matches.is_present(ANALYZE_ARG_NAME) && problem_format == "pragmatic" {
+        analyze_pragmatic_problem(problem_path, matches.values_of(MATRIX_ARG_NAME), matrix_format);
+    }
+
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
             let out_buffer = create_write_buffer(out_result);
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
+            let csv_buffer = out_csv.map(|csv| create_write_buffer(Some(csv)));
+            let html_buffer = out_html.map(|html| create_write_buffer(Some(html)));
 
             if is_get_locations_set {
                 locations_writer.0(problem_file, out_buffer).unwrap_or_else(|err| {
@@ -235,6 +333,7 @@ pub fn run_solve(matches: &ArgMatches) {
                             .with_max_generations(max_generations)
                             .with_max_time(max_time)
                             .with_cost_variation(cost_variation)
+                            .with_lower_bounds(Some(LowerBounds::new(problem.clone())))
                             .build()
                             .and_then(|solver| solver.solve())
                             .unwrap_or_else(|err| {
@@ -242,7 +341,7 @@ pub fn run_solve(matches: &ArgMatches) {
                                 process::exit(1);
                             });
 
-                        solution_writer.0(&problem, solution, out_buffer, geo_buffer).unwrap()
+                        solution_writer.0(&problem, solution, out_buffer, geo_buffer, csv_buffer, html_buffer).unwrap()
                     }
                     Err(error) => {
                         eprintln!("cannot read {} problem from '{}': '{}'", problem_format, problem_path, error);
@@ -257,3 +356,26 @@ pub fn run_solve(matches: &ArgMatches) {
         }
     }
 }
+
+fn analyze_pragmatic_problem(problem_path: &str, matrix_paths: Option<Values>, matrix_format: Option<MatrixFormat>) {
+    let problem = deserialize_problem(BufReader::new(open_file(problem_path, "problem"))).unwrap_or_else(|errors| {
+        eprintln!("cannot analyze problem: '{}'", get_errors_serialized(&errors));
+        process::exit(1);
+    });
+    let matrices = matrix_paths.map(|paths| {
+        paths
+            .map(|path| {
+                let format = matrix_format.unwrap_or_else(|| MatrixFormat::from_path(path));
+                deserialize_matrix_with_format(BufReader::new(open_file(path, "routing matrix")), format)
+                    .unwrap_or_else(|errors| {
+                        eprintln!("cannot analyze routing matrix: '{}'", get_errors_serialized(&errors));
+                        process::exit(1);
+                    })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    ValidationContext::new(&problem, matrices.as_ref()).analyze().iter().for_each(|warning| {
+        eprintln!("warning: {}", warning);
+    });
+}
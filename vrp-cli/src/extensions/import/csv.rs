@@ -65,7 +65,7 @@ fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
 fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
     let get_task = |job: &CsvJob| JobTask {
         places: vec![JobPlace {
-            location: Location { lat: job.lat, lng: job.lng },
+            location: Location::new(job.lat, job.lng),
             duration: job.duration as f64 * 60.,
             times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
         }],
@@ -107,13 +107,13 @@ fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<
     let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
         .into_iter()
         .map(|vehicle| {
-            let depot_location = Location { lat: vehicle.lat, lng: vehicle.lng };
+            let depot_location = Location::new(vehicle.lat, vehicle.lng);
 
             VehicleType {
                 type_id: vehicle.id.clone(),
                 vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                 profile: vehicle.profile,
-                costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, waiting: None, service: None },
                 shifts: vec![VehicleShift {
                     start: VehiclePlace { time: vehicle.tw_start, location: depot_location.clone() },
                     end: Some(VehiclePlace { time: vehicle.tw_end, location: depot_location }),
@@ -123,6 +123,9 @@ fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<
                 capacity: vec![vehicle.capacity],
                 skills: None,
                 limits: None,
+                parking: None,
+                service_factor: None,
+                speed_factor: None,
             }
         })
         .collect();
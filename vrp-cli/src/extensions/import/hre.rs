@@ -304,7 +304,7 @@ mod hre {
 }
 
 fn to_loc(loc: &hre::Location) -> Location {
-    Location { lat: loc.lat, lng: loc.lng }
+    Location::new(loc.lat, loc.lng)
 }
 
 pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, FormatError> {
@@ -398,6 +398,8 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         fixed: v.costs.fixed.clone(),
                         distance: v.costs.distance,
                         time: v.costs.time,
+                        waiting: None,
+                        service: None,
                     },
                     shifts: v
                         .shifts
@@ -441,6 +443,9 @@ pub fn read_hre_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Format
                         shift_time: l.shift_time.clone(),
                         allowed_areas: None,
                     }),
+                    parking: None,
+                    service_factor: None,
+                    speed_factor: None,
                 })
                 .collect(),
             profiles: hre_problem
@@ -196,7 +196,7 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
     fn remove_trivial_reloads(&self, ctx: &mut SolutionContext) {
         if ctx.required.is_empty() {
             let mut extra_ignored = Vec::new();
-            ctx.routes.iter_mut().filter(|ctx| self.multi_trip.has_reloads(ctx)).for_each(|rc| {
+            ctx.routes.iter_mut().filter(|ctx| !ctx.is_shared() && self.multi_trip.has_reloads(ctx)).for_each(|rc| {
                 let demands = (0..)
                     .zip(rc.route.tour.all_activities())
                     .filter_map(|(idx, activity)| Self::get_demand(activity).map(|_| idx))
@@ -346,7 +346,8 @@ impl<Capacity: Add<Output = Capacity> + Sub<Output = Capacity> + Ord + Copy + De
         self.conditional.accept_solution_state(ctx);
         self.remove_trivial_reloads(ctx);
 
-        ctx.routes.iter_mut().for_each(|route_ctx| {
+        // NOTE shared routes are not modified since they were accepted within original solution
+        ctx.routes.iter_mut().filter(|route_ctx| !route_ctx.is_shared()).for_each(|route_ctx| {
             self.recalculate_states(route_ctx);
         })
     }
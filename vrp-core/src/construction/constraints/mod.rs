@@ -59,6 +59,9 @@ pub const LATEST_ARRIVAL_KEY: i32 = 1;
 pub const WAITING_KEY: i32 = 2;
 pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
+pub const TOTAL_DRIVING_KEY: i32 = 5;
+pub const TOTAL_WAITING_KEY: i32 = 6;
+pub const TOTAL_SERVICE_KEY: i32 = 7;
 
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
 pub const MAX_FUTURE_CAPACITY_KEY: i32 = 12;
@@ -126,7 +126,8 @@ impl ConstraintPipeline {
 
     /// Accepts route state.
     pub fn accept_route_state(&self, ctx: &mut RouteContext) {
-        self.modules.iter().for_each(|c| c.accept_route_state(ctx))
+        self.modules.iter().for_each(|c| c.accept_route_state(ctx));
+        ctx.mark_actual();
     }
 
     /// Accepts solution state.
@@ -5,7 +5,7 @@ mod transport_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use crate::construction::OP_START_MSG;
-use crate::models::common::{Cost, Distance, Duration, Profile, Timestamp};
+use crate::models::common::{Cost, Distance, Duration, Location, Timestamp};
 use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost};
 use crate::models::solution::{Activity, TourActivity};
 use std::ops::Deref;
@@ -43,7 +43,8 @@ impl ConstraintModule for TransportConstraintModule {
     }
 
     fn accept_solution_state(&self, ctx: &mut SolutionContext) {
-        ctx.routes.iter_mut().for_each(|route_ctx| {
+        // NOTE shared routes are not modified since they were accepted within original solution
+        ctx.routes.iter_mut().filter(|route_ctx| !route_ctx.is_shared()).for_each(|route_ctx| {
             self.update_route_schedules(route_ctx);
             self.update_route_states(route_ctx);
             self.reschedule_departure(route_ctx);
@@ -84,6 +85,7 @@ impl TransportConstraintModule {
                     distance_code,
                     duration_code,
                     transport: transport.clone(),
+                    activity: activity.clone(),
                 })),
                 ConstraintVariant::SoftActivity(Arc::new(CostSoftActivityConstraint {
                     transport: transport.clone(),
@@ -103,7 +105,9 @@ impl TransportConstraintModule {
         };
 
         ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
-            a.schedule.arrival = dep + self.transport.duration(actor.vehicle.profile, loc, a.place.location, dep);
+            a.schedule.arrival = dep
+                + self.transport.duration(actor.vehicle.profile, loc, a.place.location, dep)
+                + get_parking(self.activity.as_ref(), actor.as_ref(), loc, a.deref());
             a.schedule.departure = a.schedule.arrival.max(a.place.time.start)
                 + self.activity.duration(actor.as_ref(), a.deref(), a.schedule.arrival);
 
@@ -118,6 +122,7 @@ impl TransportConstraintModule {
             actor.detail.time.end,
             actor.detail.end.unwrap_or_else(|| actor.detail.start.unwrap_or_else(|| panic!(OP_START_MSG))),
             0_f64,
+            0_f64,
         );
 
         let (route, state) = ctx.as_mut();
@@ -127,9 +132,11 @@ impl TransportConstraintModule {
                 return acc;
             }
 
-            let (end_time, prev_loc, waiting) = acc;
+            let (end_time, prev_loc, prev_parking, waiting) = acc;
+            let parking = if act.place.location != prev_loc { prev_parking } else { 0. };
             let potential_latest = end_time
                 - self.transport.duration(actor.vehicle.profile, act.place.location, prev_loc, end_time)
+                - parking
                 - self.activity.duration(actor.as_ref(), act.deref(), end_time);
 
             let latest_arrival_time = act.place.time.end.min(potential_latest);
@@ -138,7 +145,7 @@ impl TransportConstraintModule {
             state.put_activity_state(LATEST_ARRIVAL_KEY, &act, latest_arrival_time);
             state.put_activity_state(WAITING_KEY, &act, future_waiting);
 
-            (latest_arrival_time, act.place.location, future_waiting)
+            (latest_arrival_time, act.place.location, self.activity.parking(actor.as_ref(), act), future_waiting)
         });
     }
 
@@ -157,12 +164,13 @@ impl TransportConstraintModule {
         if let Some(first) = ctx.route.tour.get(1) {
             let start = ctx.route.tour.start().unwrap();
             let last_departure_time = start.schedule.departure;
-            let start_to_first = self.transport.duration(
-                ctx.route.actor.vehicle.profile,
-                start.place.location,
-                first.place.location,
-                last_departure_time,
-            );
+            let start_to_first =
+                self.transport.duration(
+                    ctx.route.actor.vehicle.profile,
+                    start.place.location,
+                    first.place.location,
+                    last_departure_time,
+                ) + get_parking(self.activity.as_ref(), ctx.route.actor.as_ref(), start.place.location, first);
             let new_departure_time = last_departure_time.max(first.place.time.start - start_to_first);
             return Some((last_departure_time, new_departure_time));
         }
@@ -175,16 +183,28 @@ impl TransportConstraintModule {
 
         let total_dur = end.schedule.arrival - start.schedule.departure;
 
-        let init = (start.place.location, start.schedule.departure, Distance::default());
-        let (_, _, total_dist) = ctx.route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
-            let total_dist =
-                total_dist + self.transport.distance(ctx.route.actor.vehicle.profile, loc, a.place.location, dep);
+        let profile = ctx.route.actor.vehicle.profile;
+        let init = (start.place.location, start.schedule.departure, Distance::default(), 0., 0.);
+        let (_, _, total_dist, total_driving, total_waiting) = ctx.route.tour.all_activities().skip(1).fold(
+            init,
+            |(loc, dep, total_dist, total_driving, total_waiting), a| {
+                let total_dist = total_dist + self.transport.distance(profile, loc, a.place.location, dep);
+                let total_driving = total_driving + self.transport.duration(profile, loc, a.place.location, dep);
+                let total_waiting = total_waiting + (a.place.time.start - a.schedule.arrival).max(0.);
+
+                (a.place.location, a.schedule.departure, total_dist, total_driving, total_waiting)
+            },
+        );
 
-            (a.place.location, a.schedule.departure, total_dist)
-        });
+        // NOTE service time includes parking and break durations, last activity departure is used as
+        // its service time is not a part of total duration when tour has no end
+        let total_service = (end.schedule.departure - start.schedule.departure - total_driving - total_waiting).max(0.);
 
         ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, total_dist);
         ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_dur);
+        ctx.state_mut().put_route_state(TOTAL_DRIVING_KEY, total_driving);
+        ctx.state_mut().put_route_state(TOTAL_WAITING_KEY, total_waiting);
+        ctx.state_mut().put_route_state(TOTAL_SERVICE_KEY, total_service);
     }
 }
 
@@ -259,8 +279,12 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             (target.place.location, target.place.time.end.min(actor.detail.time.end))
         };
 
-        let arr_time_at_next =
-            departure + self.transport.duration(profile, prev.place.location, next_act_location, departure);
+        let next_parking =
+            |from: Location| next.map_or(0., |next| get_parking(self.activity.as_ref(), actor, from, next));
+
+        let arr_time_at_next = departure
+            + self.transport.duration(profile, prev.place.location, next_act_location, departure)
+            + next_parking(prev.place.location);
 
         if arr_time_at_next > latest_arr_time_at_next_act {
             return fail(self.code);
@@ -269,8 +293,9 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             return stop(self.code);
         }
 
-        let arr_time_at_target_act =
-            departure + self.transport.duration(profile, prev.place.location, target.place.location, departure);
+        let arr_time_at_target_act = departure
+            + self.transport.duration(profile, prev.place.location, target.place.location, departure)
+            + get_parking(self.activity.as_ref(), actor, prev.place.location, target);
 
         let end_time_at_new_act = arr_time_at_target_act.max(target.place.time.start)
             + self.activity.duration(actor, target.deref(), arr_time_at_target_act);
@@ -283,6 +308,7 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                     next_act_location,
                     latest_arr_time_at_next_act,
                 )
+                - next_parking(target.place.location)
                 + self.activity.duration(actor, target.deref(), arr_time_at_target_act),
         );
 
@@ -295,7 +321,8 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
         }
 
         let arr_time_at_next_act = end_time_at_new_act
-            + self.transport.duration(profile, target.place.location, next_act_location, end_time_at_new_act);
+            + self.transport.duration(profile, target.place.location, next_act_location, end_time_at_new_act)
+            + next_parking(target.place.location);
 
         if arr_time_at_next_act > latest_arr_time_at_next_act {
             stop(self.code)
@@ -311,6 +338,7 @@ struct TravelHardActivityConstraint {
     distance_code: i32,
     duration_code: i32,
     transport: Arc<dyn TransportCost + Send + Sync>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
 }
 
 impl HardActivityConstraint for TravelHardActivityConstraint {
@@ -342,8 +370,7 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
 
 impl TravelHardActivityConstraint {
     fn calculate_travel(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let actor = &route_ctx.route.actor;
-        let profile = actor.vehicle.profile;
+        let actor = route_ctx.route.actor.as_ref();
 
         let prev = activity_ctx.prev;
         let tar = activity_ctx.target;
@@ -351,7 +378,7 @@ impl TravelHardActivityConstraint {
 
         let prev_dep = prev.schedule.departure;
 
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(profile, prev, tar, prev_dep);
+        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(actor, prev, tar, prev_dep);
         if next.is_none() {
             return (prev_to_tar_dis, prev_to_tar_dur);
         }
@@ -359,27 +386,30 @@ impl TravelHardActivityConstraint {
         let next = next.unwrap();
         let tar_dep = prev_dep + prev_to_tar_dur;
 
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(profile, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(profile, tar, next, tar_dep);
+        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(actor, prev, next, prev_dep);
+        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(actor, tar, next, tar_dep);
 
         (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
     }
 
     fn calculate_leg_travel_info(
         &self,
-        profile: Profile,
+        actor: &Actor,
         first: &TourActivity,
         second: &TourActivity,
         departure: Timestamp,
     ) -> (Distance, Duration) {
+        let profile = actor.vehicle.profile;
         let first_to_second_dis =
             self.transport.distance(profile, first.place.location, second.place.location, departure);
         let first_to_second_dur =
             self.transport.duration(profile, first.place.location, second.place.location, departure);
 
-        let second_arr = departure + first_to_second_dur;
+        let second_arr = departure
+            + first_to_second_dur
+            + get_parking(self.activity.as_ref(), actor, first.place.location, second.deref());
         let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
+        let second_dep = second_arr + second_wait + self.activity.duration(actor, second.deref(), second_arr);
 
         (first_to_second_dis, second_dep - departure)
     }
@@ -420,12 +450,15 @@ impl CostSoftActivityConstraint {
         end: &Activity,
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
-        let arrival =
-            time + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time);
+        let parking = get_parking(self.activity.as_ref(), actor, start.place.location, end);
+        let arrival = time
+            + self.transport.duration(actor.vehicle.profile, start.place.location, end.place.location, time)
+            + parking;
         let departure = arrival.max(end.place.time.start) + self.activity.duration(actor, end, arrival);
 
         let transport_cost = self.transport.cost(actor, start.place.location, end.place.location, time);
-        let activity_cost = self.activity.cost(actor, end, arrival);
+        let activity_cost = self.activity.cost(actor, end, arrival)
+            + parking * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time);
 
         (transport_cost, activity_cost, departure)
     }
@@ -470,6 +503,20 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
     }
 }
 
+/// Returns parking time at target activity: it is applied only when actor arrives from a different location.
+fn get_parking(
+    activity: &(dyn ActivityCost + Send + Sync),
+    actor: &Actor,
+    from: Location,
+    target: &Activity,
+) -> Duration {
+    if from != target.place.location {
+        activity.parking(actor, target)
+    } else {
+        0.
+    }
+}
+
 fn fail(code: i32) -> Option<ActivityConstraintViolation> {
     Some(ActivityConstraintViolation { code, stopped: true })
 }
@@ -0,0 +1,145 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/cache_test.rs"]
+mod cache_test;
+
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::{Cost, Schedule};
+use crate::models::problem::{Job, Single};
+use crate::models::solution::{Activity, Place, TourActivity};
+use hashbrown::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Specifies insertion result of the job in specific route which depends only on route itself.
+pub enum RouteInsertion {
+    /// Job can be inserted into route within given cost and activities.
+    Success {
+        /// Specifies cost of activities insertion.
+        cost: Cost,
+        /// Specifies activities within index where they have to be inserted.
+        activities: Vec<(TourActivity, usize)>,
+    },
+    /// Job cannot be inserted into route due to constraint with given code.
+    Failure {
+        /// Failed constraint code.
+        code: i32,
+    },
+}
+
+impl RouteInsertion {
+    /// Checks whether insertion is successful.
+    pub fn is_success(&self) -> bool {
+        match self {
+            Self::Success { .. } => true,
+            Self::Failure { .. } => false,
+        }
+    }
+}
+
+/// Keeps only insertion cost and activity positions, so activities are rebuilt on cache hit.
+enum CachedInsertion {
+    Success { cost: Cost, places: Vec<(Option<Arc<Single>>, Place, usize)> },
+    Failure { code: i32 },
+}
+
+impl CachedInsertion {
+    fn new(insertion: &RouteInsertion) -> Self {
+        match insertion {
+            RouteInsertion::Success { cost, activities } => Self::Success {
+                cost: *cost,
+                places: activities
+                    .iter()
+                    .map(|(activity, idx)| (activity.job.clone(), activity.place.clone(), *idx))
+                    .collect(),
+            },
+            RouteInsertion::Failure { code } => Self::Failure { code: *code },
+        }
+    }
+
+    fn to_insertion(&self) -> RouteInsertion {
+        match self {
+            Self::Success { cost, places } => RouteInsertion::Success {
+                cost: *cost,
+                activities: places
+                    .iter()
+                    .map(|(job, place, idx)| {
+                        let activity = Activity {
+                            place: place.clone(),
+                            schedule: Schedule { arrival: 0., departure: 0. },
+                            job: job.clone(),
+                        };
+                        (Box::new(activity), *idx)
+                    })
+                    .collect(),
+            },
+            Self::Failure { code } => RouteInsertion::Failure { code: *code },
+        }
+    }
+}
+
+type RouteInsertions = Arc<Mutex<HashMap<usize, CachedInsertion>>>;
+
+/// Keeps route insertion results of jobs keyed by route version, so evaluation of the same job
+/// in the route which was not modified since last evaluation can be skipped.
+///
+/// As route versions are preserved when solution is copied, the cache is effective across
+/// multiple generations.
+///
+/// Route version changes only when route or its state is modified, so hard and soft activity
+/// constraints have to depend only on the route itself and its state: a constraint which looks at
+/// other routes or solution context makes cached results stale.
+pub struct InsertionCache {
+    jobs: RwLock<HashMap<Job, RouteInsertions>>,
+    capacity: usize,
+}
+
+impl Default for InsertionCache {
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+
+impl InsertionCache {
+    /// Creates a new instance of `InsertionCache` which keeps at most `capacity` route insertions per job.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self { jobs: Default::default(), capacity }
+    }
+
+    /// Returns cached insertion result for the job in given route if route was not modified since.
+    pub fn get(&self, job: &Job, route_ctx: &RouteContext) -> Option<RouteInsertion> {
+        let insertions = self.get_route_insertions(job);
+        let insertions = insertions.lock().unwrap();
+
+        insertions.get(&route_ctx.version()).map(|insertion| insertion.to_insertion())
+    }
+
+    /// Stores insertion result for the job in given route.
+    pub fn insert(&self, job: &Job, route_ctx: &RouteContext, insertion: &RouteInsertion) {
+        let insertions = self.get_route_insertions(job);
+        let mut insertions = insertions.lock().unwrap();
+
+        if insertions.len() >= self.capacity {
+            // NOTE route versions grow monotonically, so keep only the most recent half
+            let mut versions = insertions.keys().cloned().collect::<Vec<_>>();
+            versions.sort();
+            let threshold = versions[versions.len() / 2];
+            insertions.retain(|&version, _| version >= threshold);
+        }
+
+        insertions.insert(route_ctx.version(), CachedInsertion::new(insertion));
+    }
+
+    /// Returns amount of cached route insertions for the job.
+    pub fn size(&self, job: &Job) -> usize {
+        self.jobs.read().unwrap().get(job).map_or(0, |insertions| insertions.lock().unwrap().len())
+    }
+
+    fn get_route_insertions(&self, job: &Job) -> RouteInsertions {
+        if let Some(insertions) = self.jobs.read().unwrap().get(job) {
+            return insertions.clone();
+        }
+
+        self.jobs.write().unwrap().entry(job.clone()).or_insert_with(|| Arc::new(Mutex::new(HashMap::new()))).clone()
+    }
+}
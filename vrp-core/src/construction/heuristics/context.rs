@@ -2,8 +2,9 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DRIVING_KEY, TOTAL_SERVICE_KEY, TOTAL_WAITING_KEY};
 use crate::construction::heuristics::factories::*;
+use crate::construction::heuristics::InsertionCache;
 use crate::construction::OP_START_MSG;
 use crate::models::common::{Cost, Schedule};
 use crate::models::problem::*;
@@ -13,6 +14,7 @@ use crate::utils::{as_mut, compare_floats, Random};
 use hashbrown::{HashMap, HashSet};
 use std::any::Any;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// A context which contains information needed for heuristic and metaheuristic.
@@ -25,6 +27,9 @@ pub struct InsertionContext {
 
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
+
+    /// Insertion cache shared between insertion context copies.
+    pub cache: Arc<InsertionCache>,
 }
 
 impl InsertionContext {
@@ -52,16 +57,20 @@ impl InsertionContext {
 
         self.remove_empty_routes();
 
-        self.solution.routes.iter_mut().for_each(|route_ctx| {
+        // NOTE routes which were not modified since the last state update keep their state valid
+        self.solution.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
             constraint.accept_route_state(route_ctx);
         });
     }
 
+    /// Creates a copy of insertion context. Routes are shared between the copy and
+    /// the original until one of them is modified.
     pub fn deep_copy(&self) -> Self {
         InsertionContext {
             problem: self.problem.clone(),
             solution: self.solution.deep_copy(),
             random: self.random.clone(),
+            cache: self.cache.clone(),
         }
     }
 
@@ -124,30 +133,38 @@ impl SolutionContext {
         }
     }
 
+    /// Creates a copy of solution context using copy on write strategy for routes.
     pub fn deep_copy(&self) -> Self {
         Self {
             required: self.required.clone(),
             ignored: self.ignored.clone(),
             unassigned: self.unassigned.clone(),
             locked: self.locked.clone(),
-            routes: self.routes.iter().map(|rc| rc.deep_copy()).collect(),
+            routes: self.routes.iter().map(|rc| rc.shallow_copy()).collect(),
             registry: self.registry.deep_copy(),
             state: self.state.clone(),
         }
     }
 
-    fn get_route_cost(route_ctx: &RouteContext) -> Cost {
-        let get_cost = |costs: &Costs, distance: f64, duration: f64| {
+    pub(crate) fn get_route_cost(route_ctx: &RouteContext) -> Cost {
+        let get_state = |key: i32| route_ctx.state.get_route_state::<f64>(key).cloned().unwrap_or(0.);
+
+        let distance = get_state(TOTAL_DISTANCE_KEY);
+        let driving = get_state(TOTAL_DRIVING_KEY);
+        let waiting = get_state(TOTAL_WAITING_KEY);
+        let service = get_state(TOTAL_SERVICE_KEY);
+
+        let get_cost = |costs: &Costs| {
             costs.fixed
                 + costs.per_distance * distance
-                + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+                + costs.per_driving_time * driving
+                + costs.per_waiting_time * waiting
+                + costs.per_service_time * service
         };
 
         let actor = &route_ctx.route.actor;
-        let distance = route_ctx.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
-        let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs)
     }
 }
 
@@ -159,8 +176,24 @@ pub struct RouteContext {
 
     /// Insertion state.
     pub state: Arc<RouteState>,
+
+    /// Keeps track of route sharing and its state actuality.
+    flags: Arc<RouteFlags>,
 }
 
+/// Specifies route context flags which are shared between its clones.
+struct RouteFlags {
+    /// True if route and its state are shared with another solution context.
+    is_shared: AtomicBool,
+    /// True if route was modified after its state was updated last time.
+    is_stale: AtomicBool,
+    /// Unique version of route and its state which is changed on each modification.
+    version: AtomicUsize,
+}
+
+/// Keeps track of the last used route version.
+static ROUTE_VERSION: AtomicUsize = AtomicUsize::new(0);
+
 /// Provides the way to associate arbitrary data within route and activity.
 pub struct RouteState {
     route_states: HashMap<i32, StateValue>,
@@ -170,6 +203,10 @@ pub struct RouteState {
 
 impl RouteContext {
     pub fn as_mut(&mut self) -> (&mut Route, &mut RouteState) {
+        self.ensure_owned();
+        self.flags.is_stale.store(true, Ordering::Relaxed);
+        self.flags.version.store(next_version(), Ordering::Relaxed);
+
         let route: &mut Route = unsafe { as_mut(&self.route) };
         let state: &mut RouteState = unsafe { as_mut(&self.state) };
 
@@ -177,12 +214,48 @@ impl RouteContext {
     }
 
     pub fn route_mut(&mut self) -> &mut Route {
+        self.ensure_owned();
+        self.flags.is_stale.store(true, Ordering::Relaxed);
+        self.flags.version.store(next_version(), Ordering::Relaxed);
+
         unsafe { as_mut(&self.route) }
     }
 
     pub fn state_mut(&mut self) -> &mut RouteState {
+        self.ensure_owned();
+        self.flags.version.store(next_version(), Ordering::Relaxed);
+
         unsafe { as_mut(&self.state) }
     }
+
+    /// Checks whether route and its state are shared with another solution context.
+    /// Shared route is copied on first attempt to modify it.
+    pub fn is_shared(&self) -> bool {
+        self.flags.is_shared.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether route was modified after its state was updated last time.
+    pub fn is_stale(&self) -> bool {
+        self.flags.is_stale.load(Ordering::Relaxed)
+    }
+
+    /// Returns route version which is unique for each modification of route or its state.
+    /// The version is kept when route is shared between solution copies.
+    pub fn version(&self) -> usize {
+        self.flags.version.load(Ordering::Relaxed)
+    }
+
+    /// Marks route state as actual, e.g. after it is updated by constraint pipeline.
+    pub(crate) fn mark_actual(&mut self) {
+        self.flags.is_stale.store(false, Ordering::Relaxed);
+    }
+
+    /// Replaces shared route and its state with their own copies.
+    fn ensure_owned(&mut self) {
+        if self.is_shared() {
+            *self = self.deep_copy();
+        }
+    }
 }
 
 impl RouteContext {
@@ -191,9 +264,23 @@ impl RouteContext {
         tour.set_start(create_start_activity(&actor));
         create_end_activity(&actor).map(|end| tour.set_end(end));
 
-        RouteContext { route: Arc::new(Route { actor, tour }), state: Arc::new(RouteState::default()) }
+        Self::new_with_state(Arc::new(Route { actor, tour }), Arc::new(RouteState::default()))
+    }
+
+    /// Creates route context from given route and its state.
+    pub fn new_with_state(route: Arc<Route>, state: Arc<RouteState>) -> Self {
+        RouteContext { route, state, flags: Arc::new(RouteFlags::new(false, true)) }
+    }
+
+    /// Creates a copy of route context which shares route and state with the original.
+    /// Both contexts copy route and state on first modification.
+    pub fn shallow_copy(&self) -> Self {
+        self.flags.is_shared.store(true, Ordering::Relaxed);
+
+        self.clone()
     }
 
+    /// Creates a copy of route context deeply copying its route and state.
     pub fn deep_copy(&self) -> Self {
         let new_route = Route { actor: self.route.actor.clone(), tour: self.route.tour.deep_copy() };
         let mut new_state = RouteState::new_with_sizes(self.state.sizes());
@@ -215,10 +302,28 @@ impl RouteContext {
             }
         });
 
-        RouteContext { route: Arc::new(new_route), state: Arc::new(new_state) }
+        RouteContext {
+            route: Arc::new(new_route),
+            state: Arc::new(new_state),
+            flags: Arc::new(RouteFlags::new(false, self.is_stale())),
+        }
+    }
+}
+
+impl RouteFlags {
+    fn new(is_shared: bool, is_stale: bool) -> Self {
+        Self {
+            is_shared: AtomicBool::new(is_shared),
+            is_stale: AtomicBool::new(is_stale),
+            version: AtomicUsize::new(next_version()),
+        }
     }
 }
 
+fn next_version() -> usize {
+    ROUTE_VERSION.fetch_add(1, Ordering::Relaxed) + 1
+}
+
 impl PartialEq<RouteContext> for RouteContext {
     fn eq(&self, other: &RouteContext) -> bool {
         self.route.deref() as *const Route == other.route.deref() as *const Route
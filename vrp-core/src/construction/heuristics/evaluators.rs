@@ -63,26 +63,45 @@ pub fn evaluate_job_insertion_in_route(
         }
     }
 
+    let evaluate_activities = |best_known_cost: Option<Cost>| match job {
+        Job::Single(single) => evaluate_single(single, ctx, route_ctx, position, best_known_cost),
+        Job::Multi(multi) => evaluate_multi(job, multi, ctx, route_ctx, position, best_known_cost),
+    };
+
+    let insertion = match position {
+        InsertionPosition::Any if route_ctx.route.tour.has_jobs() => {
+            ctx.cache.get(job, route_ctx).unwrap_or_else(|| {
+                let insertion = evaluate_activities(best_known_cost);
+
+                // NOTE failure can be caused by pruning with best known cost, so it is not reusable
+                if best_known_cost.is_none() || insertion.is_success() {
+                    ctx.cache.insert(job, route_ctx, &insertion);
+                }
+
+                insertion
+            })
+        }
+        _ => evaluate_activities(best_known_cost),
+    };
+
     InsertionResult::choose_best_result(
         alternative,
-        match job {
-            Job::Single(single) => {
-                evaluate_single(job, single, ctx, &route_ctx, position, route_costs, best_known_cost)
+        match insertion {
+            RouteInsertion::Success { cost, activities } => {
+                InsertionResult::make_success(cost + route_costs, job.clone(), activities, route_ctx.clone())
             }
-            Job::Multi(multi) => evaluate_multi(job, multi, ctx, &route_ctx, position, route_costs, best_known_cost),
+            RouteInsertion::Failure { code } => InsertionResult::make_failure_with_code(code, Some(job.clone())),
         },
     )
 }
 
 fn evaluate_single(
-    job: &Job,
     single: &Arc<Single>,
     ctx: &InsertionContext,
     route_ctx: &RouteContext,
     position: InsertionPosition,
-    route_costs: Cost,
     best_known_cost: Option<Cost>,
-) -> InsertionResult {
+) -> RouteInsertion {
     let mut activity = Box::new(Activity::new_with_job(single.clone()));
     let result = analyze_insertion_in_route(
         ctx,
@@ -95,10 +114,9 @@ fn evaluate_single(
 
     if result.is_success() {
         activity.place = result.place.unwrap();
-        let activities = vec![(activity, result.index)];
-        InsertionResult::make_success(result.cost.unwrap() + route_costs, job.clone(), activities, route_ctx.clone())
+        RouteInsertion::Success { cost: result.cost.unwrap(), activities: vec![(activity, result.index)] }
     } else {
-        InsertionResult::make_failure_with_code(result.violation.map_or(0, |v| v.code), Some(job.clone()))
+        RouteInsertion::Failure { code: result.violation.map_or(0, |v| v.code) }
     }
 }
 
@@ -108,9 +126,8 @@ fn evaluate_multi(
     ctx: &InsertionContext,
     route_ctx: &RouteContext,
     position: InsertionPosition,
-    route_costs: Cost,
     best_known_cost: Option<Cost>,
-) -> InsertionResult {
+) -> RouteInsertion {
     // 1. analyze permutations
     let result = unwrap_from_result(multi.permutations().into_iter().try_fold(
         MultiContext::new(best_known_cost),
@@ -156,10 +173,9 @@ fn evaluate_multi(
     ));
 
     if result.is_success() {
-        let activities = result.activities.unwrap();
-        InsertionResult::make_success(result.cost.unwrap() + route_costs, job.clone(), activities, route_ctx.clone())
+        RouteInsertion::Success { cost: result.cost.unwrap(), activities: result.activities.unwrap() }
     } else {
-        InsertionResult::make_failure_with_code(result.violation.map_or(0, |v| v.code), Some(job.clone()))
+        RouteInsertion::Failure { code: result.violation.map_or(0, |v| v.code) }
     }
 }
 
@@ -392,7 +408,7 @@ impl ShadowContext {
             is_mutated: false,
             is_dirty: false,
             problem: problem.clone(),
-            ctx: RouteContext { route: ctx.route.clone(), state: ctx.state.clone() },
+            ctx: RouteContext::new_with_state(ctx.route.clone(), ctx.state.clone()),
         }
     }
 
@@ -101,6 +101,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, random: Arc<dyn Random +
         problem: problem.clone(),
         solution: SolutionContext { required, ignored: vec![], unassigned, locked, routes, registry, state },
         random,
+        cache: Arc::new(InsertionCache::default()),
     };
 
     problem.constraint.accept_solution_state(&mut ctx.solution);
@@ -127,7 +128,7 @@ pub fn create_insertion_context_from_solution(
 
     solution.0.routes.iter().for_each(|route| {
         if route.tour.has_jobs() {
-            routes.push(RouteContext { route: Arc::new(route.deep_copy()), state: Arc::new(RouteState::default()) });
+            routes.push(RouteContext::new_with_state(Arc::new(route.deep_copy()), Arc::new(RouteState::default())));
         } else {
             registry.free_actor(&route.actor);
         }
@@ -136,5 +137,5 @@ pub fn create_insertion_context_from_solution(
     let mut solution = SolutionContext { required: jobs, ignored: vec![], unassigned, locked, routes, registry, state };
     problem.constraint.accept_solution_state(&mut solution);
 
-    InsertionContext { problem, solution, random }
+    InsertionContext { problem, solution, random, cache: Arc::new(InsertionCache::default()) }
 }
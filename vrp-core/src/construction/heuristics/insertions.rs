@@ -209,21 +209,25 @@ fn finalize_ctx(ctx: &mut InsertionContext) {
 
 fn insert(result: InsertionResult, ctx: &mut InsertionContext) {
     match result {
-        InsertionResult::Success(mut success) => {
-            let job = success.job;
+        InsertionResult::Success(success) => {
+            let InsertionSuccess { job, activities, context, .. } = success;
 
-            ctx.solution.registry.use_actor(&success.context.route.actor);
-            if !ctx.solution.routes.contains(&success.context) {
-                ctx.solution.routes.push(success.context.clone());
-            }
+            ctx.solution.registry.use_actor(&context.route.actor);
+            let route_index = ctx.solution.routes.iter().position(|rc| *rc == context).unwrap_or_else(|| {
+                ctx.solution.routes.push(context);
+                ctx.solution.routes.len() - 1
+            });
 
-            let route = success.context.route_mut();
-            success.activities.into_iter().for_each(|(a, index)| {
+            // NOTE modify route kept by solution as it might be copied on write
+            let route_ctx = ctx.solution.routes.get_mut(route_index).unwrap();
+            let route = route_ctx.route_mut();
+            activities.into_iter().for_each(|(a, index)| {
                 route.tour.insert_at(a, index + 1);
             });
 
+            let mut route_ctx = route_ctx.clone();
             ctx.solution.required.retain(|j| *j != job);
-            ctx.problem.constraint.accept_insertion(&mut ctx.solution, &mut success.context, &job);
+            ctx.problem.constraint.accept_insertion(&mut ctx.solution, &mut route_ctx, &job);
         }
         InsertionResult::Failure(failure) => {
             if let Some(job) = failure.job {
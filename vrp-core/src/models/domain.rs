@@ -8,7 +8,8 @@ use std::sync::Arc;
 /// Specifies a type used to store any values regarding problem and solution.
 pub type Extras = HashMap<String, Arc<dyn Any + Send + Sync>>;
 
-/// Defines VRP problem.
+/// Defines VRP problem. All properties are shared, so cloning creates a shallow copy.
+#[derive(Clone)]
 pub struct Problem {
     /// Specifies used fleet.
     pub fleet: Arc<Fleet>,
@@ -34,7 +34,7 @@ pub enum ActivityInfo {
 
 /// Represents specific job activity: (job, single index, place index, time window index) schema.
 pub type ActivityWithJob = (Job, usize, usize, usize);
-/// Represent specific terminal activity: (actor detail, location).
+/// Represent specific terminal activity: (actor detail, leg index) where leg index is 0 for start and 1 for end.
 pub type ActivityWithActor = (ActorDetail, usize);
 
 impl AdjacencyMatrixDecipher {
@@ -48,13 +48,14 @@ impl AdjacencyMatrixDecipher {
             actor_reverse_index: (1..).zip(problem.fleet.actors.iter().cloned()).collect(),
         };
 
+        // NOTE terminals are indexed by their leg: 0 for start and 1 for end
         get_unique_actor_details(&problem.fleet.actors).into_iter().for_each(|adk| match (adk.start, adk.end) {
             (Some(_), Some(_)) => {
                 decipher.add(ActivityInfo::Terminal((adk.clone(), 0)));
                 decipher.add(ActivityInfo::Terminal((adk, 1)));
             }
-            (None, Some(end)) => decipher.add(ActivityInfo::Terminal((adk, end))),
-            (Some(start), None) => decipher.add(ActivityInfo::Terminal((adk, start))),
+            (None, Some(_)) => decipher.add(ActivityInfo::Terminal((adk, 1))),
+            (Some(_), None) => decipher.add(ActivityInfo::Terminal((adk, 0))),
             _ => {}
         });
 
@@ -129,10 +130,13 @@ impl AdjacencyMatrixDecipher {
             ActivityInfoInserter::new(&mut ctx, &mut rc, &mut unprocessed, &mut unassigned, activity_infos).insert();
         });
 
+        // NOTE keep ignored jobs which were not inserted as ignored
+        let ignored = ctx.solution.ignored.iter().cloned().collect::<HashSet<_>>();
         ctx.solution.required = unprocessed
             .into_iter()
             .chain(unassigned.into_iter())
             .chain(ctx.solution.required.into_iter())
+            .filter(|job| !ignored.contains(job))
             .collect::<HashSet<_>>()
             .into_iter()
             .collect();
@@ -143,6 +147,25 @@ impl AdjacencyMatrixDecipher {
         ctx.solution
     }
 
+    /// Returns index of the actor which is used as a cell value in adjacency matrix.
+    pub(crate) fn get_actor_index(&self, actor: &Arc<Actor>) -> Option<usize> {
+        self.actor_direct_index.get(actor).cloned()
+    }
+
+    /// Returns index of actor's start (`is_start` is true) or end terminal.
+    pub(crate) fn get_terminal_index(&self, actor: &Arc<Actor>, is_start: bool) -> Option<usize> {
+        let (location, leg_idx) = if is_start { (actor.detail.start, 0) } else { (actor.detail.end, 1) };
+
+        location
+            .and_then(|_| self.activity_direct_index.get(&ActivityInfo::Terminal((actor.detail.clone(), leg_idx))))
+            .cloned()
+    }
+
+    /// Returns all activity infos with their indices.
+    pub(crate) fn get_activity_infos_with_indices(&self) -> impl Iterator<Item = (usize, &ActivityInfo)> + '_ {
+        self.activity_reverse_index.iter().map(|(idx, activity_info)| (*idx, activity_info))
+    }
+
     fn add(&mut self, activity_info: ActivityInfo) {
         assert_eq!(self.activity_direct_index.len(), self.activity_reverse_index.len());
 
@@ -150,7 +173,7 @@ impl AdjacencyMatrixDecipher {
         self.activity_reverse_index.insert(self.activity_reverse_index.len(), activity_info);
     }
 
-    fn dimensions(&self) -> usize {
+    pub(crate) fn dimensions(&self) -> usize {
         self.activity_direct_index.len()
     }
 
@@ -86,6 +86,7 @@ impl<'a> ActivityInfoInserter<'a> {
 
         if should_remove {
             self.insertion_ctx.solution.required.retain(|j| *j != *job);
+            self.insertion_ctx.solution.ignored.retain(|j| *j != *job);
             self.unprocessed.remove(job);
         }
     }
@@ -64,6 +64,6 @@ mod sparse_matrix;
 pub use self::sparse_matrix::*;
 
 mod decipher;
-pub use self::decipher::AdjacencyMatrixDecipher;
+pub use self::decipher::{ActivityInfo, AdjacencyMatrixDecipher};
 
 mod inserter;
@@ -73,6 +73,12 @@ pub trait ActivityCost {
     fn duration(&self, _actor: &Actor, activity: &Activity, _arrival: Timestamp) -> Cost {
         activity.place.duration
     }
+
+    /// Returns parking time spent before activity is performed. It is applied once per stop:
+    /// only when actor arrives to activity from a different location.
+    fn parking(&self, _actor: &Actor, _activity: &Activity) -> Duration {
+        0.
+    }
 }
 
 /// Default activity costs.
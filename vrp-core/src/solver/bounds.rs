@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/bounds_test.rs"]
+mod bounds_test;
+
+use crate::construction::constraints::{CapacityDimension, Demand, DemandDimension};
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Cost, Location, Profile};
+use crate::models::problem::{Costs, Job, Single};
+use crate::models::Problem;
+use crate::utils::{compare_floats, DefaultRandom};
+use std::sync::Arc;
+
+/// Specifies lower bounds of objective values which cannot be improved by any feasible solution
+/// where all required jobs are assigned.
+#[derive(Clone, Debug)]
+pub struct LowerBounds {
+    /// Minimum amount of tours.
+    pub tours: usize,
+    /// Minimum total cost.
+    pub cost: Cost,
+}
+
+/// Specifies relative difference (in percents) between solution's objective values and their lower bounds.
+#[derive(Clone, Debug)]
+pub struct OptimalityGap {
+    /// A gap of tours amount.
+    pub tours: f64,
+    /// A gap of total cost.
+    pub cost: f64,
+}
+
+impl LowerBounds {
+    /// Estimates lower bounds for given problem.
+    ///
+    /// Amount of tours is estimated as a bin-packing bound on static demand, total cost is estimated
+    /// from minimum spanning tree on job locations. Capacity bound is used only for single dimensional
+    /// capacity and when there are no conditional jobs (e.g. reloads) which can change vehicle capacity usage.
+    pub fn new(problem: Arc<Problem>) -> Self {
+        let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+        let solution = &insertion_ctx.solution;
+
+        let jobs = solution
+            .required
+            .iter()
+            .cloned()
+            .chain(solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()))
+            .collect::<Vec<_>>();
+
+        let tours = if jobs.is_empty() {
+            0
+        } else if solution.ignored.is_empty() {
+            get_tours_lower_bound(problem.as_ref(), jobs.as_slice()).max(1)
+        } else {
+            1
+        };
+
+        Self::new_with_tours(problem.as_ref(), jobs.as_slice(), tours)
+    }
+
+    /// Estimates lower bounds for given jobs using given amount of tours.
+    pub fn new_with_tours(problem: &Problem, jobs: &[Job], tours: usize) -> Self {
+        let actors = problem.fleet.actors.as_slice();
+        let get_min_rate = |rate_fn: fn(&Costs) -> f64| {
+            actors
+                .iter()
+                .map(|actor| rate_fn(&actor.vehicle.costs) + rate_fn(&actor.driver.costs))
+                .min_by(|a, b| compare_floats(*a, *b))
+                .unwrap_or(0.)
+        };
+
+        let fixed = get_min_rate(|costs| costs.fixed);
+        let per_distance = get_min_rate(|costs| costs.per_distance);
+        let per_time = get_min_rate(|costs| costs.per_driving_time);
+
+        let nodes = get_nodes(problem, jobs);
+        let distance = get_spanning_tree_weight(problem, nodes.as_slice(), |profile, from, to| {
+            problem.transport.distance(profile, from, to, 0.)
+        });
+        let duration = get_spanning_tree_weight(problem, nodes.as_slice(), |profile, from, to| {
+            problem.transport.duration(profile, from, to, 0.)
+        });
+
+        Self { tours, cost: tours as f64 * fixed + per_distance * distance + per_time * duration }
+    }
+}
+
+impl OptimalityGap {
+    /// Calculates optimality gap of given solution. Returns none if solution has unassigned jobs
+    /// as lower bounds are not applicable for it.
+    pub fn new(bounds: &LowerBounds, insertion_ctx: &InsertionContext) -> Option<Self> {
+        if !insertion_ctx.solution.unassigned.is_empty() {
+            return None;
+        }
+
+        let get_gap = |value: f64, bound: f64| if value > 0. { (value - bound).max(0.) / value * 100. } else { 0. };
+
+        Some(Self {
+            tours: get_gap(insertion_ctx.solution.routes.len() as f64, bounds.tours as f64),
+            cost: get_gap(insertion_ctx.solution.get_total_cost(), bounds.cost),
+        })
+    }
+}
+
+/// Returns minimum amount of tours needed to serve static demand of given jobs with
+/// single dimensional capacity.
+fn get_tours_lower_bound(problem: &Problem, jobs: &[Job]) -> usize {
+    let mut capacities = problem
+        .fleet
+        .actors
+        .iter()
+        .filter_map(|actor| actor.vehicle.dimens.get_capacity().cloned())
+        .collect::<Vec<i32>>();
+
+    if capacities.len() != problem.fleet.actors.len() {
+        return 1;
+    }
+
+    capacities.sort_by(|a, b| b.cmp(a));
+
+    let (pickup, delivery) = get_singles(jobs).fold((0, 0), |(pickup, delivery), single| {
+        let demand: Option<&Demand<i32>> = single.dimens.get_demand();
+        demand.map_or((pickup, delivery), |demand| (pickup + demand.pickup.0, delivery + demand.delivery.0))
+    });
+
+    let get_bins = |total: i32| {
+        capacities
+            .iter()
+            .scan(0, |acc, capacity| {
+                *acc += *capacity;
+                Some(*acc)
+            })
+            .position(|acc| acc >= total)
+            .map_or(capacities.len(), |idx| idx + 1)
+    };
+
+    get_bins(pickup).max(get_bins(delivery))
+}
+
+/// Returns job locations where the first node represents all vehicle terminals.
+fn get_nodes(problem: &Problem, jobs: &[Job]) -> Vec<Vec<Location>> {
+    let actors = problem.fleet.actors.as_slice();
+    let terminals = if actors.iter().all(|actor| actor.detail.start.is_some()) {
+        actors.iter().flat_map(|actor| actor.detail.start.iter().chain(actor.detail.end.iter()).cloned()).collect()
+    } else {
+        vec![]
+    };
+
+    // NOTE job which can be served at any location does not contribute to the bound
+    std::iter::once(terminals)
+        .chain(
+            get_singles(jobs)
+                .filter(|single| single.places.iter().all(|place| place.location.is_some()))
+                .map(|single| single.places.iter().filter_map(|place| place.location).collect()),
+        )
+        .collect()
+}
+
+fn get_singles<'a>(jobs: &'a [Job]) -> impl Iterator<Item = Arc<Single>> + 'a {
+    jobs.iter().flat_map(|job| match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    })
+}
+
+/// Calculates weight of minimum spanning tree using Prim's algorithm. The weight of an edge between
+/// two nodes is the minimum value between any of their locations in any direction and for any profile.
+fn get_spanning_tree_weight<F>(problem: &Problem, nodes: &[Vec<Location>], value_fn: F) -> f64
+where
+    F: Fn(Profile, Location, Location) -> f64,
+{
+    if nodes.len() < 2 {
+        return 0.;
+    }
+
+    let profiles = problem.fleet.profiles.as_slice();
+    let get_weight = |from: &[Location], to: &[Location]| {
+        if from.is_empty() || to.is_empty() {
+            return 0.;
+        }
+
+        profiles
+            .iter()
+            .flat_map(|&profile| {
+                let value_fn = &value_fn;
+                from.iter()
+                    .flat_map(move |&a| to.iter().map(move |&b| value_fn(profile, a, b).min(value_fn(profile, b, a))))
+            })
+            .min_by(|a, b| compare_floats(*a, *b))
+            .unwrap_or(0.)
+    };
+
+    let mut in_tree = vec![false; nodes.len()];
+    let mut weights = vec![f64::MAX; nodes.len()];
+    let mut current = 0;
+    let mut total = 0.;
+
+    in_tree[current] = true;
+
+    for _ in 1..nodes.len() {
+        let mut next: Option<(usize, f64)> = None;
+
+        for idx in 0..nodes.len() {
+            if in_tree[idx] {
+                continue;
+            }
+
+            weights[idx] = weights[idx].min(get_weight(nodes[current].as_slice(), nodes[idx].as_slice()));
+
+            if weights[idx] < next.map_or(f64::MAX, |(_, weight)| weight) {
+                next = Some((idx, weights[idx]));
+            }
+        }
+
+        let (idx, weight) = next.unwrap();
+        in_tree[idx] = true;
+        current = idx;
+        total += weight;
+    }
+
+    total
+}
@@ -4,9 +4,9 @@ use crate::models::{Problem, Solution};
 use crate::solver::evolution::EvolutionConfig;
 use crate::solver::mutation::*;
 use crate::solver::termination::*;
-use crate::solver::Solver;
+use crate::solver::{LowerBounds, Solver};
 use crate::utils::{DefaultRandom, TimeQuota};
-use std::ops::Deref;
+use std::ops::{Add, Deref, Sub};
 use std::sync::Arc;
 
 /// Provides configurable way to build solver.
@@ -15,6 +15,8 @@ pub struct Builder {
     max_time: Option<usize>,
     cost_variation: Option<(usize, f64)>,
     problem: Option<Arc<Problem>>,
+    lower_bounds: Option<LowerBounds>,
+    ant_colony: Option<usize>,
     config: EvolutionConfig,
 }
 
@@ -25,6 +27,8 @@ impl Default for Builder {
             max_time: None,
             cost_variation: None,
             problem: None,
+            lower_bounds: None,
+            ant_colony: None,
             config: EvolutionConfig {
                 mutation: Box::new(RuinAndRecreateMutation::default()),
                 termination: Box::new(MaxTime::new(300.)),
@@ -39,6 +43,7 @@ impl Default for Builder {
                     (Box::new(RecreateWithBlinks::<i32>::default()), 5),
                 ],
                 initial_individuals: vec![],
+                post_mutation: None,
                 random: Arc::new(DefaultRandom::default()),
                 logger: Arc::new(|msg| println!("{}", msg)),
             },
@@ -130,6 +135,45 @@ impl Builder {
         self
     }
 
+    /// Sets max amount of jobs in the route which order is optimized exactly in the best solution
+    /// once evolution is finished. Capacity type should be the same as used by capacity constraint.
+    /// Default is None.
+    pub fn with_exact_route_optimization<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static>(
+        mut self,
+        max_route_size: Option<usize>,
+    ) -> Self {
+        if let Some(size) = max_route_size {
+            self.config.logger.deref()(format!("configured to use exact route optimization for size={}", size));
+        }
+        self.config.post_mutation =
+            max_route_size.map(|size| Box::new(ExactRouteOptimization::<Capacity>::new(size)) as Box<dyn Mutation>);
+        self
+    }
+
+    /// Sets amount of ants used by ant colony optimization which constructs initial solutions and
+    /// is used as an alternative mutation method.
+    /// Default is None.
+    pub fn with_ant_colony(mut self, ants: Option<usize>) -> Self {
+        if let Some(ants) = ants {
+            self.config.logger.deref()(format!("configured to use ant colony with ants={}", ants));
+        }
+        self.ant_colony = ants;
+        self
+    }
+
+    /// Sets lower bounds used to report optimality gap of the best solution.
+    /// Default is None.
+    pub fn with_lower_bounds(mut self, lower_bounds: Option<LowerBounds>) -> Self {
+        if let Some(bounds) = lower_bounds.as_ref() {
+            self.config.logger.deref()(format!(
+                "lower bounds are estimated as tours: {}, cost: {:.2}",
+                bounds.tours, bounds.cost
+            ));
+        }
+        self.lower_bounds = lower_bounds;
+        self
+    }
+
     /// Builds solver with parameters specified.
     pub fn build(self) -> Result<Solver, String> {
         let problem = self.problem.ok_or_else(|| "problem is not specified".to_string())?;
@@ -171,10 +215,18 @@ impl Builder {
                 }
             };
 
+        if let Some(ants) = self.ant_colony {
+            let ant_colony = AntColony::new(problem.clone(), ants);
+            let mutation = std::mem::replace(&mut config.mutation, Box::new(RuinAndRecreateMutation::default()));
+
+            config.initial_methods.push((Box::new(ant_colony.clone()), 10));
+            config.mutation = Box::new(CompositeMutation::new(vec![(mutation, 95), (Box::new(ant_colony), 5)]));
+        }
+
         config.termination = Box::new(CompositeTermination::new(criterias));
         config.quota = quota;
 
-        Ok(Solver { problem, config })
+        Ok(Solver { problem, config, lower_bounds: self.lower_bounds })
     }
 }
 
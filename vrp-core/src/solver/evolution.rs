@@ -32,6 +32,8 @@ pub struct EvolutionConfig {
     pub initial_methods: Vec<(Box<dyn Recreate>, usize)>,
     /// Initial individuals in population.
     pub initial_individuals: Vec<InsertionContext>,
+    /// A mutation applied to the best individual once evolution is finished.
+    pub post_mutation: Option<Box<dyn Mutation>>,
 
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
@@ -63,6 +65,13 @@ pub fn run_evolution(problem: Arc<Problem>, config: EvolutionConfig) -> Result<B
         refinement_ctx.generation += 1;
     }
 
+    if let Some(post_mutation) = config.post_mutation.as_ref() {
+        if let Some(insertion_ctx) = refinement_ctx.population.best().map(|ctx| ctx.deep_copy()) {
+            let insertion_ctx = post_mutation.mutate(&mut refinement_ctx, insertion_ctx);
+            refinement_ctx.population.add(insertion_ctx);
+        }
+    }
+
     log_result(&refinement_ctx, &evolution_time, &config.logger);
 
     Ok(refinement_ctx.population)
@@ -12,10 +12,12 @@ pub mod mutation;
 pub mod objectives;
 pub mod termination;
 
+mod bounds;
 mod builder;
 mod evolution;
 mod population;
 
+pub use self::bounds::{LowerBounds, OptimalityGap};
 pub use self::builder::Builder;
 pub use self::population::DominancePopulation;
 use std::ops::Deref;
@@ -77,6 +79,8 @@ pub type Logger = Arc<dyn Fn(String) -> ()>;
 pub struct Solver {
     pub problem: Arc<Problem>,
     pub config: EvolutionConfig,
+    /// Lower bounds used to estimate optimality gap of the best solution.
+    pub lower_bounds: Option<LowerBounds>,
 }
 
 impl Solver {
@@ -87,14 +91,25 @@ impl Solver {
 
         // NOTE select first best according to population
         let insertion_ctx = population.best().ok_or_else(|| "cannot find any solution".to_string())?;
-        let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
+        let gap = self.lower_bounds.as_ref().and_then(|bounds| OptimalityGap::new(bounds, insertion_ctx));
+
+        let extras = if let Some(gap) = gap.as_ref() {
+            let mut extras = self.problem.extras.as_ref().clone();
+            extras.insert("optimality_gap".to_string(), Arc::new(gap.clone()));
+            Arc::new(extras)
+        } else {
+            self.problem.extras.clone()
+        };
+
+        let solution = insertion_ctx.solution.to_solution(extras);
         let cost = self.problem.objective.fitness(insertion_ctx);
 
         logger.deref()(format!(
-            "best solution has cost: {}, tours: {}, unassigned: {}",
+            "best solution has cost: {}, tours: {}, unassigned: {}{}",
             cost,
             solution.routes.len(),
-            solution.unassigned.len()
+            solution.unassigned.len(),
+            gap.map_or_else(|| "".to_string(), |gap| format!(", gap: tours {:.2}%, cost {:.2}%", gap.tours, gap.cost))
         ));
 
         Ok((solution, cost))
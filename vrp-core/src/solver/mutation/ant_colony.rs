@@ -0,0 +1,273 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/ant_colony_test.rs"]
+mod ant_colony_test;
+
+use crate::construction::heuristics::{InsertionCache, InsertionContext};
+use crate::models::common::{Location, Objective};
+use crate::models::matrix::{ActivityInfo, AdjacencyMatrix, AdjacencyMatrixDecipher, SparseMatrix};
+use crate::models::problem::{Actor, Job};
+use crate::models::Problem;
+use crate::solver::mutation::{Mutation, Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use crate::utils::{compare_floats, Random};
+use hashbrown::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// An ant colony optimization method which learns structure of good solutions in form of
+/// pheromone adjacency matrix.
+///
+/// Each ant builds routes as sequences of activities choosing the next activity with probability
+/// proportional to pheromone and inverse distance. Such sequences are encoded in adjacency matrix
+/// which might define an unfeasible solution, so it is decoded using `AdjacencyMatrixDecipher`
+/// and jobs which cannot be kept are inserted using cheapest insertion. Pheromone is evaporated
+/// and reinforced by the best known solution every time the method is called (MAX-MIN ant system).
+///
+/// Clones share the same pheromone matrix, so the same colony can be used as initial and
+/// mutation method at the same time. Used as recreate method, it ignores the given solution and
+/// constructs a new one from scratch.
+#[derive(Clone)]
+pub struct AntColony {
+    problem: Arc<Problem>,
+    decipher: Arc<AdjacencyMatrixDecipher>,
+    pheromone: Arc<RwLock<PheromoneMatrix>>,
+    activities: Arc<Vec<JobActivity>>,
+    recreate: Arc<RecreateWithCheapest>,
+    ants: usize,
+}
+
+/// Specifies job activity which can be visited by ant.
+#[derive(Clone)]
+struct JobActivity {
+    index: usize,
+    key: (Job, usize),
+    location: Option<Location>,
+}
+
+/// A sparse pheromone matrix where missing cells have minimum pheromone value.
+struct PheromoneMatrix {
+    data: HashMap<usize, HashMap<usize, f64>>,
+}
+
+const PHEROMONE_MIN: f64 = 0.01;
+const PHEROMONE_MAX: f64 = 1.;
+const EVAPORATION_RATE: f64 = 0.1;
+const PHEROMONE_WEIGHT: i32 = 1;
+const DISTANCE_WEIGHT: i32 = 2;
+
+impl AntColony {
+    /// Creates a new instance of `AntColony` where `ants` solutions are constructed on each
+    /// call and the best of them is returned.
+    pub fn new(problem: Arc<Problem>, ants: usize) -> Self {
+        assert!(ants > 0);
+
+        let decipher = AdjacencyMatrixDecipher::new(problem.clone());
+        let activities = decipher
+            .get_activity_infos_with_indices()
+            .filter_map(|(index, activity_info)| match activity_info {
+                ActivityInfo::Job((job, single_idx, place_idx, _)) => {
+                    let single = match job {
+                        Job::Single(single) => single.clone(),
+                        Job::Multi(multi) => multi.jobs.get(*single_idx).cloned().unwrap(),
+                    };
+                    let location = single.places.get(*place_idx).and_then(|place| place.location);
+
+                    Some(JobActivity { index, key: (job.clone(), *single_idx), location })
+                }
+                ActivityInfo::Terminal(_) => None,
+            })
+            .collect();
+
+        Self {
+            problem,
+            decipher: Arc::new(decipher),
+            pheromone: Arc::new(RwLock::new(PheromoneMatrix::new(0))),
+            activities: Arc::new(activities),
+            recreate: Arc::new(RecreateWithCheapest::default()),
+            ants,
+        }
+    }
+
+    /// Updates pheromone using the best known solution and constructs a new solution.
+    pub fn create(
+        &self,
+        refinement_ctx: &mut RefinementContext,
+        random: Arc<dyn Random + Send + Sync>,
+    ) -> InsertionContext {
+        if let Some(best) = refinement_ctx.population.best() {
+            self.update(best);
+        }
+
+        let insertion_ctxs = (0..self.ants)
+            .map(|_| {
+                let matrix = self.construct(random.as_ref());
+                let insertion_ctx = InsertionContext {
+                    problem: self.problem.clone(),
+                    solution: self.decipher.decode(&matrix),
+                    random: random.clone(),
+                    cache: Arc::new(InsertionCache::default()),
+                };
+
+                self.recreate.run(refinement_ctx, insertion_ctx)
+            })
+            .collect::<Vec<_>>();
+
+        let objective = self.problem.objective.clone();
+
+        insertion_ctxs
+            .into_iter()
+            .map(|insertion_ctx| (objective.fitness(&insertion_ctx), insertion_ctx))
+            .min_by(|(a, _), (b, _)| compare_floats(*a, *b))
+            .map(|(_, insertion_ctx)| insertion_ctx)
+            .unwrap()
+    }
+
+    /// Evaporates pheromone and reinforces it on edges used by given solution.
+    fn update(&self, insertion_ctx: &InsertionContext) {
+        let matrix: SparseMatrix = self.decipher.encode(&insertion_ctx.solution);
+        let mut pheromone = self.pheromone.write().unwrap();
+
+        pheromone.evaporate();
+        matrix.data.iter().for_each(|(row, cells)| cells.iter().for_each(|(col, _)| pheromone.reinforce(*row, *col)));
+    }
+
+    /// Constructs adjacency matrix of a potentially unfeasible solution.
+    fn construct(&self, random: &dyn Random) -> SparseMatrix {
+        let pheromone = self.pheromone.read().unwrap();
+        let mut matrix = SparseMatrix::new(self.decipher.dimensions());
+        let mut activities = self.activities.iter().collect::<Vec<_>>();
+
+        let mut actors = self.problem.fleet.actors.iter().collect::<Vec<_>>();
+        (1..actors.len()).rev().for_each(|idx| actors.swap(idx, random.uniform_int(0, idx as i32) as usize));
+
+        for actor in actors {
+            if activities.is_empty() {
+                break;
+            }
+
+            let (actor_idx, start_idx) =
+                match (self.decipher.get_actor_index(actor), self.decipher.get_terminal_index(actor, true)) {
+                    (Some(actor_idx), Some(start_idx)) => (actor_idx as f64, start_idx),
+                    _ => continue,
+                };
+            let end_idx = self.decipher.get_terminal_index(actor, false);
+
+            // NOTE an open route is closed virtually by returning to its start
+            let close = (end_idx.unwrap_or(start_idx), actor.detail.end.or(actor.detail.start));
+            let mut current = (start_idx, actor.detail.start);
+
+            loop {
+                let mut weights = activities
+                    .iter()
+                    .map(|activity| self.get_weight(&pheromone, actor, current, (activity.index, activity.location)))
+                    .collect::<Vec<_>>();
+
+                if current.0 != start_idx {
+                    weights.push(self.get_weight(&pheromone, actor, current, close));
+                }
+
+                match select_index(weights.as_slice(), random).filter(|idx| *idx < activities.len()) {
+                    Some(idx) => {
+                        let activity = activities[idx];
+                        matrix.set_cell(current.0, activity.index, actor_idx);
+                        activities.retain(|other| other.key != activity.key);
+                        current = (activity.index, activity.location.or(current.1));
+                    }
+                    None => {
+                        if let Some(end_idx) = end_idx.filter(|_| current.0 != start_idx) {
+                            matrix.set_cell(current.0, end_idx, actor_idx);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+
+        matrix
+    }
+
+    fn get_weight(
+        &self,
+        pheromone: &PheromoneMatrix,
+        actor: &Actor,
+        from: (usize, Option<Location>),
+        to: (usize, Option<Location>),
+    ) -> f64 {
+        let distance = match (from.1, to.1) {
+            (Some(from), Some(to)) => self.problem.transport.distance(actor.vehicle.profile, from, to, 0.),
+            _ => 0.,
+        };
+
+        pheromone.get_cell(from.0, to.0).powi(PHEROMONE_WEIGHT) * (1. / (1. + distance.max(0.))).powi(DISTANCE_WEIGHT)
+    }
+}
+
+impl Recreate for AntColony {
+    fn run(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.create(refinement_ctx, insertion_ctx.random.clone())
+    }
+}
+
+impl Mutation for AntColony {
+    fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.create(refinement_ctx, insertion_ctx.random.clone())
+    }
+}
+
+impl PheromoneMatrix {
+    fn get_cell(&self, row: usize, col: usize) -> f64 {
+        self.data.get(&row).and_then(|cells| cells.get(&col)).cloned().unwrap_or(PHEROMONE_MIN)
+    }
+
+    fn evaporate(&mut self) {
+        self.data.values_mut().for_each(|cells| {
+            cells.values_mut().for_each(|value| *value *= 1. - EVAPORATION_RATE);
+            cells.retain(|_, value| *value > PHEROMONE_MIN);
+        });
+        self.data.retain(|_, cells| !cells.is_empty());
+    }
+
+    fn reinforce(&mut self, row: usize, col: usize) {
+        let value = (self.get_cell(row, col) + EVAPORATION_RATE * PHEROMONE_MAX).min(PHEROMONE_MAX);
+        self.set_cell(row, col, value);
+    }
+}
+
+impl AdjacencyMatrix for PheromoneMatrix {
+    fn new(_: usize) -> Self {
+        Self { data: Default::default() }
+    }
+
+    fn values<'a>(&'a self) -> Box<dyn Iterator<Item = f64> + 'a> {
+        Box::new(self.data.values().flat_map(|cells| cells.values().cloned()))
+    }
+
+    fn set_cell(&mut self, row: usize, col: usize, value: f64) {
+        self.data.entry(row).or_default().insert(col, value);
+    }
+
+    fn scan_row<F>(&self, row: usize, predicate: F) -> Option<usize>
+    where
+        F: Fn(f64) -> bool,
+    {
+        self.data.get(&row).and_then(|cells| cells.iter().filter(|(_, v)| predicate(**v)).map(|(col, _)| *col).min())
+    }
+}
+
+/// Selects index using roulette wheel selection.
+fn select_index(weights: &[f64], random: &dyn Random) -> Option<usize> {
+    let total = weights.iter().sum::<f64>();
+    if weights.is_empty() || total <= 0. {
+        return None;
+    }
+
+    let target = random.uniform_real(0., total);
+
+    weights
+        .iter()
+        .scan(0., |acc, weight| {
+            *acc += *weight;
+            Some(*acc)
+        })
+        .position(|acc| acc > target)
+        .or(Some(weights.len() - 1))
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/exact_route_optimization_test.rs"]
+mod exact_route_optimization_test;
+
+use crate::construction::constraints::{MAX_PAST_CAPACITY_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::heuristics::{ActivityContext, InsertionContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Distance, Duration, Timestamp};
+use crate::models::problem::{Job, Single};
+use crate::models::solution::{Activity, Place};
+use crate::models::Problem;
+use crate::solver::mutation::Mutation;
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::sync::Arc;
+
+/// A mutation which finds optimal order of activities in small routes using dynamic programming
+/// approach similar to Held-Karp algorithm. Partial tours are extended only when all hard activity
+/// constraints from constraint pipeline are fulfilled, so time windows, capacity and other
+/// problem specific limitations are respected. Capacity type is used to compare loads of partial tours.
+pub struct ExactRouteOptimization<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
+    max_route_size: usize,
+    phantom: PhantomData<Capacity>,
+}
+
+/// Represents partial tour which visits a subset of route jobs.
+struct Label<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> {
+    route_ctx: RouteContext,
+    cost: Cost,
+    departure: Timestamp,
+    distance: Distance,
+    duration: Duration,
+    max_load: Capacity,
+}
+
+impl<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> Default for ExactRouteOptimization<Capacity> {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> ExactRouteOptimization<Capacity> {
+    /// Creates a new instance of `ExactRouteOptimization` which optimizes routes with at most
+    /// `max_route_size` jobs. As complexity grows exponentially, the size is limited to 16.
+    pub fn new(max_route_size: usize) -> Self {
+        assert!(max_route_size > 0 && max_route_size <= 16);
+
+        Self { max_route_size, phantom: PhantomData }
+    }
+
+    /// Optimizes all suitable routes in given solution.
+    pub fn optimize(&self, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        let problem = insertion_ctx.problem.clone();
+
+        let routes = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| self.is_suitable(route_ctx, &insertion_ctx.solution.locked))
+            .filter_map(|(idx, route_ctx)| self.optimize_route(problem.as_ref(), route_ctx).map(|rc| (idx, rc)))
+            .collect::<Vec<_>>();
+
+        if !routes.is_empty() {
+            routes.into_iter().for_each(|(idx, route_ctx)| insertion_ctx.solution.routes[idx] = route_ctx);
+            problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+        }
+
+        insertion_ctx
+    }
+
+    /// Returns a new route context with optimal order of activities or none if there is no
+    /// order cheaper than existing one.
+    pub fn optimize_route(&self, problem: &Problem, route_ctx: &RouteContext) -> Option<RouteContext> {
+        let singles = route_ctx.route.tour.all_activities().filter_map(|a| a.job.clone()).collect::<Vec<_>>();
+
+        let mut empty_ctx = RouteContext::new(route_ctx.route.actor.clone());
+        problem.constraint.accept_route_state(&mut empty_ctx);
+
+        // NOTE labels are grouped by visited jobs (as bit mask) and last visited job
+        let initial = vec![((0_usize, 0_usize), vec![Label::new(empty_ctx, 0)])].into_iter().collect();
+
+        let labels = (0..singles.len()).fold(initial, |labels: HashMap<(usize, usize), Vec<Label<Capacity>>>, _| {
+            labels.iter().fold(HashMap::new(), |mut acc, ((mask, _), labels)| {
+                singles.iter().enumerate().filter(|(idx, _)| mask & (1 << idx) == 0).for_each(|(idx, single)| {
+                    labels.iter().flat_map(|label| extend_label(problem, label, single)).for_each(|label| {
+                        add_label(acc.entry((mask | (1 << idx), idx)).or_insert_with(Vec::new), label)
+                    });
+                });
+
+                acc
+            })
+        });
+
+        let original_cost = SolutionContext::get_route_cost(route_ctx);
+
+        labels
+            .into_iter()
+            .flat_map(|(_, labels)| labels.into_iter())
+            .min_by(|a, b| compare_floats(a.cost, b.cost))
+            .filter(|label| compare_floats(label.cost, original_cost) == Ordering::Less)
+            .map(|label| label.route_ctx)
+    }
+
+    fn is_suitable(&self, route_ctx: &RouteContext, locked: &HashSet<Job>) -> bool {
+        let tour = &route_ctx.route.tour;
+        let activity_count = tour.activity_count();
+
+        // NOTE activities of multi jobs have implicit order which is not checked by constraints
+        activity_count > 1
+            && activity_count <= self.max_route_size
+            && activity_count == tour.job_count()
+            && tour.jobs().all(|job| !locked.contains(&job) && job.as_single().is_some())
+    }
+}
+
+impl<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> Mutation for ExactRouteOptimization<Capacity> {
+    fn mutate(&self, _: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        self.optimize(insertion_ctx)
+    }
+}
+
+impl<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static> Label<Capacity> {
+    fn new(route_ctx: RouteContext, index: usize) -> Self {
+        let cost = SolutionContext::get_route_cost(&route_ctx);
+        let last = route_ctx.route.tour.get(index).unwrap();
+        let departure = last.schedule.departure;
+
+        let state = &route_ctx.state;
+        let distance = state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
+        let duration = state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+        let max_load = state.get_activity_state::<Capacity>(MAX_PAST_CAPACITY_KEY, last).cloned().unwrap_or_default();
+
+        Self { route_ctx, cost, departure, distance, duration, max_load }
+    }
+
+    /// Checks whether the label is not worse than other one with the same visited jobs and last job.
+    /// Besides cost and departure, loads and limits accumulated so far are compared as they define
+    /// whether the rest of jobs can be appended: e.g. static delivery increases load of the whole prefix.
+    fn dominates(&self, other: &Label<Capacity>) -> bool {
+        self.cost <= other.cost
+            && self.departure <= other.departure
+            && self.distance <= other.distance
+            && self.duration <= other.duration
+            && self.max_load <= other.max_load
+    }
+}
+
+/// Appends job to the partial tour trying all job places and time windows.
+fn extend_label<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static>(
+    problem: &Problem,
+    label: &Label<Capacity>,
+    single: &Arc<Single>,
+) -> Vec<Label<Capacity>> {
+    let route_ctx = &label.route_ctx;
+    let (items, index) = route_ctx.route.tour.legs().last().unwrap();
+    let (prev, next) = match items {
+        [prev] => (prev, None),
+        [prev, next] => (prev, Some(next)),
+        _ => panic!("Unexpected route leg configuration."),
+    };
+    let start_time = route_ctx.route.tour.start().unwrap().schedule.departure;
+
+    single
+        .places
+        .iter()
+        .flat_map(|detail| {
+            detail.times.iter().map(move |time| Place {
+                location: detail.location.unwrap_or(prev.place.location),
+                duration: detail.duration,
+                time: time.to_time_window(start_time),
+            })
+        })
+        .filter_map(|place| {
+            let mut activity = Box::new(Activity::new_with_job(single.clone()));
+            activity.place = place;
+
+            let activity_ctx = ActivityContext { index, prev, target: &activity, next };
+            if problem.constraint.evaluate_hard_activity(route_ctx, &activity_ctx).is_some() {
+                return None;
+            }
+
+            let mut new_route_ctx = route_ctx.deep_copy();
+            new_route_ctx.route_mut().tour.insert_at(activity, index + 1);
+            problem.constraint.accept_route_state(&mut new_route_ctx);
+
+            Some(Label::new(new_route_ctx, index + 1))
+        })
+        .collect()
+}
+
+/// Adds label to the list keeping only non-dominated ones.
+fn add_label<Capacity: Add + Sub + Ord + Copy + Default + Send + Sync + 'static>(
+    labels: &mut Vec<Label<Capacity>>,
+    label: Label<Capacity>,
+) {
+    if labels.iter().any(|other| other.dominates(&label)) {
+        return;
+    }
+
+    labels.retain(|other| !label.dominates(other));
+    labels.push(label);
+}
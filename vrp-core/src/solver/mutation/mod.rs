@@ -7,6 +7,12 @@ pub use self::recreate::*;
 mod ruin;
 pub use self::ruin::*;
 
+mod exact_route_optimization;
+pub use self::exact_route_optimization::ExactRouteOptimization;
+
+mod ant_colony;
+pub use self::ant_colony::AntColony;
+
 /// Mutates given insertion context.
 pub trait Mutation {
     fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext;
@@ -38,3 +44,25 @@ impl Mutation for RuinAndRecreateMutation {
         self.recreate.run(refinement_ctx, insertion_ctx)
     }
 }
+
+/// Provides the way to run one of multiple mutation methods.
+pub struct CompositeMutation {
+    mutations: Vec<Box<dyn Mutation>>,
+    weights: Vec<usize>,
+}
+
+impl CompositeMutation {
+    /// Creates a new instance of [`CompositeMutation`].
+    pub fn new(mutations: Vec<(Box<dyn Mutation>, usize)>) -> Self {
+        let weights = mutations.iter().map(|(_, weight)| *weight).collect();
+        let mutations = mutations.into_iter().map(|(mutation, _)| mutation).collect();
+        Self { mutations, weights }
+    }
+}
+
+impl Mutation for CompositeMutation {
+    fn mutate(&self, refinement_ctx: &mut RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let index = insertion_ctx.random.weighted(self.weights.as_slice());
+        self.mutations.get(index).unwrap().mutate(refinement_ctx, insertion_ctx)
+    }
+}
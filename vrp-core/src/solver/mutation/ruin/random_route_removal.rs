@@ -23,24 +23,27 @@ impl RandomRouteRemoval {
         Self { rmin: rmin as f64, rmax: rmax as f64, threshold }
     }
 
-    fn remove_whole_route(&self, solution: &mut SolutionContext, route_ctx: &mut RouteContext) {
+    fn remove_whole_route(&self, solution: &mut SolutionContext, route_ctx: &RouteContext) {
         solution.routes.retain(|rc| rc != route_ctx);
         solution.registry.free_actor(&route_ctx.route.actor);
         solution.required.extend(route_ctx.route.tour.jobs());
     }
 
-    fn remove_part_route(&self, insertion_ctx: &mut InsertionContext, route_ctx: &mut RouteContext) {
+    fn remove_part_route(&self, insertion_ctx: &mut InsertionContext, route_index: usize) {
         let solution = &mut insertion_ctx.solution;
         let locked = solution.locked.clone();
+        let route_ctx = solution.routes.get(route_index).unwrap().clone();
 
         let can_remove_full_route = route_ctx.route.tour.jobs().all(|job| !locked.contains(&job));
 
         if can_remove_full_route {
-            self.remove_whole_route(solution, route_ctx);
+            self.remove_whole_route(solution, &route_ctx);
         } else {
             {
                 let jobs: Vec<Job> = route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).collect();
 
+                // NOTE modify route kept by solution as it might be copied on write
+                let route_ctx = solution.routes.get_mut(route_index).unwrap();
                 jobs.iter().for_each(|job| {
                     route_ctx.route_mut().tour.remove(job);
                 });
@@ -68,12 +71,12 @@ impl Ruin for RandomRouteRemoval {
         (0..affected).for_each(|_| {
             let mut solution = &mut insertion_ctx.solution;
             let route_index = insertion_ctx.random.uniform_int(0, (solution.routes.len() - 1) as i32) as usize;
-            let route_ctx = &mut solution.routes.get(route_index).unwrap().clone();
+            let route_ctx = solution.routes.get(route_index).unwrap().clone();
 
             if solution.locked.is_empty() {
-                self.remove_whole_route(&mut solution, route_ctx);
+                self.remove_whole_route(&mut solution, &route_ctx);
             } else {
-                self.remove_part_route(&mut insertion_ctx, route_ctx);
+                self.remove_part_route(&mut insertion_ctx, route_index);
             }
         });
 
@@ -43,7 +43,7 @@ impl Ruin for WorstJobRemoval {
             !solution.locked.contains(job) && !solution.unassigned.contains_key(job)
         };
 
-        let mut route_jobs = get_route_jobs(&insertion_ctx.solution);
+        let route_jobs = get_route_jobs(&insertion_ctx.solution);
         let mut routes_savings = get_routes_cost_savings(&insertion_ctx);
         let removed_jobs: RwLock<HashSet<Job>> = RwLock::new(HashSet::default());
 
@@ -67,18 +67,20 @@ impl Ruin for WorstJobRemoval {
                         .take(remove)
                         .for_each(|job| {
                             // NOTE job can be absent if it is unassigned
-                            if let Some(rc) = route_jobs.get_mut(&job) {
-                                // NOTE actual insertion context modification via route mut
-                                if rc.route_mut().tour.remove(&job) {
-                                    removed_jobs.write().unwrap().insert(job);
-                                }
+                            if route_jobs.contains_key(&job) {
+                                removed_jobs.write().unwrap().insert(job);
                             }
                         });
                 }
             },
         );
 
-        removed_jobs.write().unwrap().iter().for_each(|job| insertion_ctx.solution.required.push(job.clone()));
+        // NOTE actual insertion context modification via route kept by solution as it might be copied on write
+        removed_jobs.into_inner().unwrap().into_iter().for_each(|job| {
+            let route_index = route_jobs.get(&job).cloned().unwrap();
+            insertion_ctx.solution.routes.get_mut(route_index).unwrap().route_mut().tour.remove(&job);
+            insertion_ctx.solution.required.push(job);
+        });
 
         insertion_ctx
     }
@@ -92,11 +94,12 @@ impl WorstJobRemoval {
     }
 }
 
-fn get_route_jobs(solution: &SolutionContext) -> HashMap<Job, RouteContext> {
+fn get_route_jobs(solution: &SolutionContext) -> HashMap<Job, usize> {
     solution
         .routes
         .iter()
-        .flat_map(|rc| rc.route.tour.jobs().collect::<Vec<_>>().into_iter().map(move |job| (job, rc.clone())))
+        .enumerate()
+        .flat_map(|(idx, rc)| rc.route.tour.jobs().collect::<Vec<_>>().into_iter().map(move |job| (job, idx)))
         .collect()
 }
 
@@ -1,5 +1,5 @@
 use crate::construction::constraints::ConstraintPipeline;
-use crate::construction::heuristics::{InsertionContext, RouteContext, SolutionContext};
+use crate::construction::heuristics::{InsertionCache, InsertionContext, RouteContext, SolutionContext};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
 use crate::helpers::models::domain::{create_empty_problem_with_constraint, create_empty_solution_context};
 use crate::models::solution::Registry;
@@ -15,6 +15,7 @@ pub fn create_insertion_context(
         problem: create_empty_problem_with_constraint(constraint),
         solution: SolutionContext { routes, registry, ..create_empty_solution_context() },
         random: Arc::new(DefaultRandom::default()),
+        cache: Arc::new(InsertionCache::default()),
     }
 }
 
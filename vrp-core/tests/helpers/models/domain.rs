@@ -1,5 +1,5 @@
 use crate::construction::constraints::ConstraintPipeline;
-use crate::construction::heuristics::{InsertionContext, SolutionContext};
+use crate::construction::heuristics::{InsertionCache, InsertionContext, SolutionContext};
 use crate::helpers::models::problem::*;
 use crate::models::common::IdDimension;
 use crate::models::problem::{Fleet, Job, Jobs, ObjectiveCost};
@@ -58,6 +58,7 @@ pub fn create_empty_insertion_context() -> InsertionContext {
         problem: create_empty_problem(),
         solution: create_empty_solution_context(),
         random: Arc::new(DefaultRandom::default()),
+        cache: Arc::new(InsertionCache::default()),
     }
 }
 
@@ -101,7 +101,7 @@ pub fn create_route_context_with_activities(
 ) -> RouteContext {
     let route = create_route_with_activities(fleet, vehicle, activities);
 
-    RouteContext { route: Arc::new(route), state: Arc::new(RouteState::default()) }
+    RouteContext::new_with_state(Arc::new(route), Arc::new(RouteState::default()))
 }
 
 fn create_route(actor: Arc<Actor>, mut tour: Tour, activities: Vec<TourActivity>) -> Route {
@@ -0,0 +1,85 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_test_route_ctx() -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+
+    create_route_context_with_activities(&fleet, "v1", vec![test_tour_activity_with_location(1)])
+}
+
+fn get_code(insertion: RouteInsertion) -> i32 {
+    match insertion {
+        RouteInsertion::Failure { code } => code,
+        RouteInsertion::Success { .. } => unreachable!(),
+    }
+}
+
+#[test]
+fn can_reuse_insertion_for_the_same_route_version() {
+    let cache = InsertionCache::default();
+    let job = Job::Single(test_single_with_id("job1"));
+    let route_ctx = create_test_route_ctx();
+
+    cache.insert(&job, &route_ctx, &RouteInsertion::Failure { code: 1 });
+    let result = cache.get(&job, &route_ctx.shallow_copy());
+
+    assert_eq!(get_code(result.unwrap()), 1);
+    assert_eq!(cache.size(&job), 1);
+}
+
+#[test]
+fn can_evaluate_insertion_again_when_route_is_modified() {
+    let cache = InsertionCache::default();
+    let job = Job::Single(test_single_with_id("job1"));
+    let mut route_ctx = create_test_route_ctx();
+
+    cache.insert(&job, &route_ctx, &RouteInsertion::Failure { code: 1 });
+    route_ctx.route_mut();
+
+    assert!(cache.get(&job, &route_ctx).is_none());
+    cache.insert(&job, &route_ctx, &RouteInsertion::Failure { code: 2 });
+    assert_eq!(get_code(cache.get(&job, &route_ctx).unwrap()), 2);
+    assert_eq!(cache.size(&job), 2);
+}
+
+#[test]
+fn can_keep_limited_amount_of_insertions() {
+    let cache = InsertionCache::new(4);
+    let job = Job::Single(test_single_with_id("job1"));
+    let mut route_ctx = create_test_route_ctx();
+
+    (0..10).for_each(|_| {
+        route_ctx.state_mut();
+        cache.insert(&job, &route_ctx, &RouteInsertion::Failure { code: 1 });
+    });
+
+    assert!(cache.size(&job) <= 4);
+    assert_eq!(get_code(cache.get(&job, &route_ctx).unwrap()), 1);
+}
+
+#[test]
+fn can_rebuild_activities_of_cached_success_insertion() {
+    let cache = InsertionCache::default();
+    let single = test_single_with_id("job1");
+    let job = Job::Single(single.clone());
+    let route_ctx = create_test_route_ctx();
+    let mut activity = Activity::new_with_job(single);
+    activity.place.location = 5;
+    activity.schedule.arrival = 10.;
+
+    cache.insert(&job, &route_ctx, &RouteInsertion::Success { cost: 3., activities: vec![(Box::new(activity), 1)] });
+    let result = cache.get(&job, &route_ctx).unwrap();
+
+    match result {
+        RouteInsertion::Success { cost, activities } => {
+            assert_eq!(cost, 3.);
+            assert_eq!(activities.len(), 1);
+            assert_eq!(activities[0].0.place.location, 5);
+            assert_eq!(activities[0].0.schedule.arrival, 0.);
+            assert_eq!(activities[0].1, 1);
+            assert!(activities[0].0.has_same_job(&job));
+        }
+        RouteInsertion::Failure { .. } => unreachable!(),
+    }
+}
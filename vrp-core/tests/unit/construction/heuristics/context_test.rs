@@ -1,6 +1,9 @@
-use crate::construction::heuristics::RouteState;
+use crate::construction::heuristics::{InsertionContext, RouteState};
 use crate::helpers::models::solution::test_activity;
+use crate::helpers::solver::generate_matrix_routes;
 use crate::models::solution::TourActivity;
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
 
 fn new_tour_activity_ref() -> TourActivity {
     Box::new(test_activity())
@@ -82,3 +85,53 @@ fn can_remove_activity_states() {
     assert!(result1.is_none());
     assert!(result2.is_none());
 }
+
+fn create_insertion_ctx_with_routes(rows: usize, cols: usize) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(rows, cols);
+    let mut insertion_ctx = InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (Arc::new(solution), None),
+        Arc::new(DefaultRandom::default()),
+    );
+    insertion_ctx.restore();
+
+    insertion_ctx
+}
+
+#[test]
+fn can_share_routes_between_copies_until_modified() {
+    let original = create_insertion_ctx_with_routes(2, 2);
+
+    let mut copy = original.deep_copy();
+    original.solution.routes.iter().zip(copy.solution.routes.iter()).for_each(|(lhs, rhs)| {
+        assert!(Arc::ptr_eq(&lhs.route, &rhs.route));
+        assert!(Arc::ptr_eq(&lhs.state, &rhs.state));
+        assert!(rhs.is_shared());
+    });
+
+    let job = copy.solution.routes[0].route.tour.jobs().next().unwrap();
+    copy.solution.routes[0].route_mut().tour.remove(&job);
+
+    assert!(!Arc::ptr_eq(&original.solution.routes[0].route, &copy.solution.routes[0].route));
+    assert!(!copy.solution.routes[0].is_shared());
+    assert!(Arc::ptr_eq(&original.solution.routes[1].route, &copy.solution.routes[1].route));
+    assert_eq!(original.solution.routes[0].route.tour.job_count(), 2);
+    assert_eq!(copy.solution.routes[0].route.tour.job_count(), 1);
+}
+
+#[test]
+fn can_restore_only_modified_routes() {
+    let original = create_insertion_ctx_with_routes(2, 2);
+    assert!(original.solution.routes.iter().all(|rc| !rc.is_stale()));
+
+    let mut copy = original.deep_copy();
+    let job = copy.solution.routes[0].route.tour.jobs().next().unwrap();
+    copy.solution.routes[0].route_mut().tour.remove(&job);
+    assert!(copy.solution.routes[0].is_stale());
+
+    copy.restore();
+
+    assert!(copy.solution.routes.iter().all(|rc| !rc.is_stale()));
+    assert!(Arc::ptr_eq(&original.solution.routes[1].route, &copy.solution.routes[1].route));
+    assert!(Arc::ptr_eq(&original.solution.routes[1].state, &copy.solution.routes[1].state));
+}
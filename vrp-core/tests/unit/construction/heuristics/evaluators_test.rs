@@ -99,6 +99,25 @@ mod single {
         }
     }
 
+    #[test]
+    fn can_cache_insertion_only_when_it_is_not_pruned_by_best_known_cost() {
+        let registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        route_ctx.route_mut().tour.insert_at(create_tour_activity_at(5), 1).insert_at(create_tour_activity_at(10), 2);
+        let ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![route_ctx]);
+        let route_ctx = ctx.solution.routes.first().unwrap();
+        let job = Job::Single(test_single_with_location(Some(7)));
+        let best_known = InsertionResult::make_success(0., job.clone(), vec![], route_ctx.clone());
+
+        let result = evaluate_job_insertion_in_route(&job, &ctx, route_ctx, InsertionPosition::Any, Some(best_known));
+        assert!(matches!(result, InsertionResult::Success(success) if success.activities.is_empty()));
+        assert_eq!(ctx.cache.size(&job), 0);
+
+        let result = evaluate_job_insertion_in_route(&job, &ctx, route_ctx, InsertionPosition::Any, None);
+        assert!(matches!(result, InsertionResult::Success(success) if success.activities.len() == 1));
+        assert_eq!(ctx.cache.size(&job), 1);
+    }
+
     parameterized_test! {can_insert_job_with_two_vehicles_and_various_time_constraints, (job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost), {
         can_insert_job_with_two_vehicles_and_various_time_constraints_impl(job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost);
     }}
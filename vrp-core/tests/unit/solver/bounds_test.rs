@@ -0,0 +1,77 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::construction::constraints::create_simple_demand;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::models::problem::{Fleet, Jobs, ObjectiveCost};
+
+fn create_test_problem(fleet: Fleet, jobs: Vec<Job>) -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let fleet = Arc::new(fleet);
+    let jobs = Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport));
+
+    Arc::new(Problem {
+        fleet,
+        jobs,
+        locks: vec![],
+        constraint: Arc::new(ConstraintPipeline::default()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    })
+}
+
+fn create_test_job(location: Location, demand: i32) -> Job {
+    SingleBuilder::default().location(Some(location)).demand(create_simple_demand(-demand)).build_as_job_ref()
+}
+
+#[test]
+fn can_estimate_cost_lower_bound_from_spanning_tree() {
+    let jobs = vec![create_test_job(3, 1), create_test_job(1, 1), create_test_job(4, 1), create_test_job(2, 1)];
+    let problem = create_test_problem(test_fleet(), jobs);
+
+    let bounds = LowerBounds::new(problem);
+
+    assert_eq!(bounds.tours, 1);
+    assert_eq!(bounds.cost, 16.);
+}
+
+parameterized_test! {can_estimate_tours_lower_bound_from_capacity, (capacities, demands, expected), {
+    can_estimate_tours_lower_bound_from_capacity_impl(capacities, demands, expected);
+}}
+
+can_estimate_tours_lower_bound_from_capacity! {
+    case01: (vec![10, 10, 5], vec![8, 8, 8], 3),
+    case02: (vec![10, 10, 5], vec![5, 5, 5, 5], 2),
+    case03: (vec![10, 5, 10], vec![1, 1], 1),
+    case04: (vec![10], vec![8, 8, 8], 1),
+}
+
+fn can_estimate_tours_lower_bound_from_capacity_impl(capacities: Vec<i32>, demands: Vec<i32>, expected: usize) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(
+            capacities.into_iter().map(|capacity| VehicleBuilder::default().capacity(capacity).build()).collect(),
+        )
+        .build();
+    let jobs = demands.into_iter().enumerate().map(|(idx, demand)| create_test_job(idx + 1, demand)).collect();
+    let problem = create_test_problem(fleet, jobs);
+
+    let bounds = LowerBounds::new(problem);
+
+    assert_eq!(bounds.tours, expected);
+}
+
+#[test]
+fn can_calculate_optimality_gap_only_without_unassigned_jobs() {
+    let bounds = LowerBounds { tours: 1, cost: 16. };
+    let mut insertion_ctx = create_empty_insertion_context();
+
+    let gap = OptimalityGap::new(&bounds, &insertion_ctx).unwrap();
+    assert_eq!(gap.tours, 0.);
+    assert_eq!(gap.cost, 0.);
+
+    insertion_ctx.solution.unassigned.insert(create_test_job(1, 1), 0);
+    assert!(OptimalityGap::new(&bounds, &insertion_ctx).is_none());
+}
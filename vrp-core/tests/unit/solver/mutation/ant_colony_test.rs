@@ -0,0 +1,81 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::{create_default_refinement_ctx, create_with_cheapest};
+use crate::models::problem::{Jobs, ObjectiveCost};
+use crate::utils::DefaultRandom;
+
+fn create_test_problem() -> Arc<Problem> {
+    let transport = TestTransportCost::new_shared();
+    let fleet = Arc::new(
+        FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+            .build(),
+    );
+    let jobs = (1..6)
+        .map(|idx| SingleBuilder::default().id(format!("job{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect();
+    let jobs = Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport));
+
+    Arc::new(Problem {
+        fleet,
+        jobs,
+        locks: vec![],
+        constraint: Arc::new(create_constraint_pipeline_with_transport()),
+        activity: Arc::new(TestActivityCost::default()),
+        transport,
+        objective: Arc::new(ObjectiveCost::default()),
+        extras: Arc::new(Default::default()),
+    })
+}
+
+#[test]
+fn can_construct_solution_with_all_jobs() {
+    let problem = create_test_problem();
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let ant_colony = AntColony::new(problem.clone(), 2);
+
+    let insertion_ctx = Recreate::run(
+        &ant_colony,
+        &mut refinement_ctx,
+        InsertionContext::new(problem, Arc::new(DefaultRandom::default())),
+    );
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        5
+    );
+}
+
+#[test]
+fn can_reinforce_pheromone_with_best_solution() {
+    let problem = create_test_problem();
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    refinement_ctx.population.add(create_with_cheapest(problem.clone(), Arc::new(DefaultRandom::default())));
+    let ant_colony = AntColony::new(problem.clone(), 1);
+    let best: SparseMatrix = ant_colony.decipher.encode(&refinement_ctx.population.best().unwrap().solution);
+
+    ant_colony.mutate(&mut refinement_ctx, InsertionContext::new(problem, Arc::new(DefaultRandom::default())));
+
+    let pheromone = ant_colony.pheromone.read().unwrap();
+    assert!(!best.data.is_empty());
+    best.data.iter().for_each(|(row, cells)| {
+        cells.iter().for_each(|(col, _)| assert!(pheromone.get_cell(*row, *col) > PHEROMONE_MIN));
+    });
+}
+
+#[test]
+fn can_evaporate_pheromone() {
+    let mut pheromone = PheromoneMatrix::new(2);
+
+    pheromone.reinforce(0, 1);
+    assert_eq!(pheromone.get_cell(0, 1), PHEROMONE_MIN + EVAPORATION_RATE);
+    assert_eq!(pheromone.scan_row(0, |value| value > PHEROMONE_MIN), Some(1));
+
+    (0..100).for_each(|_| pheromone.evaporate());
+    assert_eq!(pheromone.get_cell(0, 1), PHEROMONE_MIN);
+    assert!(pheromone.values().next().is_none());
+}
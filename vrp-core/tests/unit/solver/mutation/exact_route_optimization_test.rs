@@ -0,0 +1,137 @@
+use super::*;
+use crate::construction::constraints::CapacityConstraintModule;
+use crate::construction::heuristics::InsertionCache;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, Schedule, TimeWindow};
+use crate::models::problem::{Fleet, VehicleDetail};
+use crate::utils::DefaultRandom;
+
+fn create_test_fleet() -> Fleet {
+    FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build()
+}
+
+fn create_test_route_ctx(problem: &Problem, fleet: &Fleet, locations: Vec<Location>, narrow: Location) -> RouteContext {
+    let activities = locations
+        .into_iter()
+        .map(|location| {
+            let time = if location == narrow { TimeWindow::new(0., location as f64) } else { TimeWindow::max() };
+            let job = SingleBuilder::default()
+                .id(format!("job{}", location).as_str())
+                .location(Some(location))
+                .times(vec![time.clone()])
+                .build();
+
+            Box::new(Activity {
+                place: Place { location, duration: 0., time },
+                schedule: Schedule::new(0., 0.),
+                job: Some(Arc::new(job)),
+            })
+        })
+        .collect();
+
+    let mut route_ctx = create_route_context_with_activities(fleet, "v1", activities);
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn get_job_ids(route_ctx: &RouteContext) -> Vec<String> {
+    route_ctx.route.tour.all_activities().filter_map(|a| a.retrieve_job()).map(|job| get_customer_id(&job)).collect()
+}
+
+parameterized_test! {can_optimize_route_order, (locations, narrow, expected), {
+    can_optimize_route_order_impl(locations, narrow, expected);
+}}
+
+can_optimize_route_order! {
+    case01: (vec![3, 1, 4, 2], 0, Some(32.)),
+    case02: (vec![3, 4, 1, 2], 1, Some(32.)),
+    case03: (vec![4, 2, 1, 3], 4, Some(32.)),
+    case04: (vec![1, 2, 3, 4], 1, None),
+    case05: (vec![2, 1], 0, None),
+}
+
+fn can_optimize_route_order_impl(locations: Vec<Location>, narrow: Location, expected: Option<Cost>) {
+    let fleet = create_test_fleet();
+    let problem = create_empty_problem_with_constraint_and_fleet(create_constraint_pipeline_with_transport(), fleet);
+    let route_ctx = create_test_route_ctx(problem.as_ref(), problem.fleet.as_ref(), locations, narrow);
+
+    let result = ExactRouteOptimization::<i32>::default().optimize_route(problem.as_ref(), &route_ctx);
+
+    assert_eq!(result.as_ref().map(SolutionContext::get_route_cost), expected);
+    if let Some(result) = result {
+        assert_eq!(get_job_ids(&result).len(), route_ctx.route.tour.job_count());
+        assert!(result
+            .route
+            .tour
+            .all_activities()
+            .filter(|a| a.job.is_some() && a.place.location == narrow)
+            .all(|a| a.schedule.arrival <= narrow as f64));
+    }
+}
+
+#[test]
+fn can_skip_routes_with_too_many_jobs() {
+    let fleet = create_test_fleet();
+    let problem = create_empty_problem_with_constraint_and_fleet(create_constraint_pipeline_with_transport(), fleet);
+    let route_ctx = create_test_route_ctx(problem.as_ref(), problem.fleet.as_ref(), vec![3, 4, 1, 2], 1);
+    let insertion_ctx = InsertionContext {
+        problem: problem.clone(),
+        solution: SolutionContext { routes: vec![route_ctx], ..create_empty_solution_context() },
+        random: Arc::new(DefaultRandom::default()),
+        cache: Arc::new(InsertionCache::default()),
+    };
+
+    let insertion_ctx = ExactRouteOptimization::<i32>::new(3).optimize(insertion_ctx);
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["job3", "job4", "job1", "job2"]]);
+
+    let insertion_ctx = ExactRouteOptimization::<i32>::new(4).optimize(insertion_ctx);
+    assert_eq!(get_job_ids(&insertion_ctx.solution.routes[0]).first().unwrap(), "job1");
+    assert_eq!(insertion_ctx.solution.get_total_cost(), 32.);
+}
+
+#[test]
+fn can_keep_more_expensive_prefix_when_cheapest_one_cannot_be_completed() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            VehicleBuilder::default()
+                .id("v1")
+                .capacity(3)
+                .details(vec![VehicleDetail { start: Some(0), end: None, time: Some(DEFAULT_ACTOR_TIME_WINDOW) }])
+                .build(),
+        )
+        .build();
+    let mut constraint = create_constraint_pipeline_with_transport();
+    constraint.add_module(Box::new(CapacityConstraintModule::<i32>::new(2)));
+    let problem = create_empty_problem_with_constraint_and_fleet(constraint, fleet);
+    // NOTE static pickup at 1 can be visited only after any static delivery, so cheapest prefix
+    // with the pickup visited first is not extendable with static delivery at 4
+    let activities = vec![(4, -2), (3, 0), (2, -1), (1, 1)]
+        .into_iter()
+        .map(|(location, demand)| {
+            let job = SingleBuilder::default()
+                .id(format!("job{}", location).as_str())
+                .location(Some(location))
+                .demand(create_simple_demand(demand))
+                .build();
+
+            Box::new(Activity {
+                place: Place { location, duration: 0., time: TimeWindow::max() },
+                schedule: Schedule::new(0., 0.),
+                job: Some(Arc::new(job)),
+            })
+        })
+        .collect();
+    let mut route_ctx = create_route_context_with_activities(problem.fleet.as_ref(), "v1", activities);
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    let result = ExactRouteOptimization::<i32>::default().optimize_route(problem.as_ref(), &route_ctx);
+
+    let result = result.expect("cannot find better route");
+    assert_eq!(get_job_ids(&result), vec!["job2", "job1", "job3", "job4"]);
+    assert_eq!(SolutionContext::get_route_cost(&result), 24.);
+}
@@ -1,4 +1,4 @@
-use crate::construction::heuristics::{InsertionContext, RouteContext, RouteState, SolutionContext};
+use crate::construction::heuristics::{InsertionCache, InsertionContext, RouteContext, RouteState, SolutionContext};
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
@@ -21,8 +21,8 @@ fn can_calculate_transport_cost() {
             .add_vehicle(VehicleBuilder::default().id("v2").costs(fixed_costs()).build())
             .build(),
     );
-    let route1 = RouteContext {
-        route: Arc::new(create_route_with_start_end_activities(
+    let route1 = RouteContext::new_with_state(
+        Arc::new(create_route_with_start_end_activities(
             &fleet,
             "v1",
             test_tour_activity_with_schedule(Schedule::new(0., 0.)),
@@ -32,18 +32,18 @@ fn can_calculate_transport_cost() {
                 test_tour_activity_with_location_and_duration(15, 5.),
             ],
         )),
-        state: Arc::new(RouteState::default()),
-    };
-    let route2 = RouteContext {
-        route: Arc::new(create_route_with_start_end_activities(
+        Arc::new(RouteState::default()),
+    );
+    let route2 = RouteContext::new_with_state(
+        Arc::new(create_route_with_start_end_activities(
             &fleet,
             "v2",
             test_tour_activity_with_schedule(Schedule::new(0., 0.)),
             test_tour_activity_with_schedule(Schedule::new(11., 11.)),
             vec![test_tour_activity_with_location_and_duration(5, 1.)],
         )),
-        state: Arc::new(RouteState::default()),
-    };
+        Arc::new(RouteState::default()),
+    );
     let activity = Arc::new(SimpleActivityCost::default());
     let transport = TestTransportCost::new_shared();
     let constraint = Arc::new(create_constraint_pipeline_with_transport());
@@ -68,6 +68,7 @@ fn can_calculate_transport_cost() {
             ..create_empty_solution_context()
         },
         random: Arc::new(DefaultRandom::default()),
+        cache: Arc::new(InsertionCache::default()),
     };
     constraint.accept_solution_state(&mut insertion_ctx.solution);
 
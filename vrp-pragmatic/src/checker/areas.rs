@@ -0,0 +1,78 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/areas_test.rs"]
+mod areas_test;
+
+use super::*;
+
+/// Checks that vehicles serve jobs only inside their allowed areas.
+pub fn check_tour_areas(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
+        let vehicle = context.get_vehicle(tour.vehicle_id.as_str())?;
+        let areas = match vehicle.limits.as_ref().and_then(|limits| limits.allowed_areas.as_ref()) {
+            Some(areas) => areas,
+            None => return Ok(()),
+        };
+
+        tour.stops.iter().enumerate().for_each(|(stop_idx, stop)| {
+            stop.activities
+                .iter()
+                .enumerate()
+                .filter(|(_, activity)| context.get_job_by_id(activity.job_id.as_str()).is_some())
+                .for_each(|(activity_idx, activity)| {
+                    let location = get_location(stop, activity);
+
+                    let is_inside = areas
+                        .iter()
+                        .map(|area| is_location_in_area(&location, area))
+                        .collect::<Option<Vec<_>>>()
+                        .map(|results| results.into_iter().any(|is_inside| is_inside));
+
+                    let message = match is_inside {
+                        Some(true) => return,
+                        Some(false) => format!(
+                            "Job '{}' is served at '{:?}' outside of allowed areas of vehicle '{}'",
+                            activity.job_id, location, tour.vehicle_id
+                        ),
+                        None => format!(
+                            "Cannot check allowed areas of vehicle '{}' for job '{}' served at '{:?}': \
+                             areas can be used only with geocoordinates",
+                            tour.vehicle_id, activity.job_id, location
+                        ),
+                    };
+
+                    violations.push(TourViolation::new(message, stop_idx, Some(activity_idx)));
+                })
+        });
+
+        Ok(())
+    })
+}
+
+/// Checks whether given location is inside area (polygon) using ray casting algorithm.
+/// Returns `None` if location or any area point is not represented by geocoordinates.
+fn is_location_in_area(location: &Location, area: &[Location]) -> Option<bool> {
+    let (x, y) = get_lat_lng(location)?;
+
+    let mut is_inside = false;
+    let mut j = area.len().max(1) - 1;
+
+    for (i, point) in area.iter().enumerate() {
+        let (ix, iy) = get_lat_lng(point)?;
+        let (jx, jy) = get_lat_lng(&area[j])?;
+
+        if ((ix > x) != (jx > x)) && (y < (jy - iy) * (x - ix) / (jx - ix) + iy) {
+            is_inside = !is_inside;
+        }
+
+        j = i;
+    }
+
+    Some(is_inside)
+}
+
+fn get_lat_lng(location: &Location) -> Option<(f64, f64)> {
+    match location {
+        Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+        Location::Reference { .. } => None,
+    }
+}
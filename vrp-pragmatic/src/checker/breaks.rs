@@ -5,52 +5,21 @@ mod breaks_test;
 use super::*;
 
 /// Checks that breaks are properly assigned.
-pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
-    context.solution.tours.iter().try_for_each(|tour| {
+pub fn check_tour_breaks(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
         let vehicle_shift = context.get_vehicle_shift(tour)?;
-        let actual_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
-            stop.activities.windows(2).flat_map(|leg| as_leg_with_break(context, tour, stop, leg)).try_fold(
-                acc,
-                |acc, (from, to, vehicle_break)| {
-                    // check time
-                    let visit_time = get_time_window(stop, to);
-                    let break_time_window = get_break_time_window(tour, &vehicle_break)?;
-                    if !visit_time.intersects(&break_time_window) {
-                        return Err(format!(
-                            "Break visit time '{:?}' is invalid: expected is in '{:?}'",
-                            visit_time, break_time_window
-                        ));
-                    }
-
-                    // check location
-                    let actual_location = get_location(stop, to);
-                    match &vehicle_break.locations {
-                        Some(locations) => {
-                            let is_correct =
-                                locations.iter().any(|location| same_locations(&actual_location, location));
-
-                            if !is_correct {
-                                return Err(format!(
-                                    "Break location '{:?}' is invalid: expected one of '{:?}'",
-                                    actual_location, locations
-                                ));
-                            }
-                        }
-                        None => {
-                            let prev_location = get_location(stop, from);
-                            if !same_locations(&prev_location, &actual_location) {
-                                return Err(format!(
-                                    "Break location '{:?}' is invalid: expected previous activity location '{:?}'",
-                                    actual_location, prev_location
-                                ));
-                            }
-                        }
-                    }
-
-                    Ok(acc + 1)
-                },
-            )
-        })?;
+        let mut actual_break_count = 0;
+
+        for (stop_idx, stop) in tour.stops.iter().enumerate() {
+            for (from, to, vehicle_break) in
+                stop.activities.windows(2).flat_map(|leg| as_leg_with_break(context, tour, stop, leg))
+            {
+                actual_break_count += 1;
+
+                check_break(tour, stop, from, to, &vehicle_break)
+                    .unwrap_or_else(|message| violations.push(TourViolation::new(message, stop_idx, None)));
+            }
+        }
 
         let arrival = tour
             .stops
@@ -70,16 +39,64 @@ pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
             });
 
         if expected_break_count != actual_break_count {
-            Err(format!(
-                "Amount of breaks does not match, expected: '{}', got '{}'",
-                expected_break_count, actual_break_count
-            ))
-        } else {
-            Ok(())
+            violations.push(
+                format!(
+                    "Amount of breaks does not match, expected: '{}', got '{}'",
+                    expected_break_count, actual_break_count
+                )
+                .into(),
+            );
         }
+
+        Ok(())
     })
 }
 
+/// Checks break's visit time and location.
+fn check_break(
+    tour: &Tour,
+    stop: &Stop,
+    from: &Activity,
+    to: &Activity,
+    vehicle_break: &VehicleBreak,
+) -> Result<(), String> {
+    // check time
+    let visit_time = get_time_window(stop, to);
+    let break_time_window = get_break_time_window(tour, vehicle_break)?;
+    if !visit_time.intersects(&break_time_window) {
+        return Err(format!(
+            "Break visit time '{:?}' is invalid: expected is in '{:?}'",
+            visit_time, break_time_window
+        ));
+    }
+
+    // check location
+    let actual_location = get_location(stop, to);
+    match &vehicle_break.locations {
+        Some(locations) => {
+            let is_correct = locations.iter().any(|location| same_locations(&actual_location, location));
+
+            if !is_correct {
+                return Err(format!(
+                    "Break location '{:?}' is invalid: expected one of '{:?}'",
+                    actual_location, locations
+                ));
+            }
+        }
+        None => {
+            let prev_location = get_location(stop, from);
+            if !same_locations(&prev_location, &actual_location) {
+                return Err(format!(
+                    "Break location '{:?}' is invalid: expected previous activity location '{:?}'",
+                    actual_location, prev_location
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn as_leg_with_break<'a>(
     context: &CheckerContext,
     tour: &Tour,
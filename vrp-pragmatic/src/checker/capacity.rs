@@ -9,8 +9,8 @@ use std::iter::once;
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
-pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
-    context.solution.tours.iter().try_for_each(|tour| {
+pub fn check_tour_load(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
         let capacity = Capacity::new(context.get_vehicle(tour.vehicle_id.as_str())?.capacity.clone());
 
         let legs = (0_usize..)
@@ -44,40 +44,38 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
             })
             .collect::<Vec<_>>();
 
-        intervals
-            .iter()
-            .try_fold::<_, _, Result<_, String>>(Capacity::default(), |acc, interval| {
-                let (start_delivery, end_pickup) = interval
-                    .iter()
-                    .flat_map(|(_, (from, to))| once(from).chain(once(to)))
-                    .zip(0..)
-                    .filter_map(|(stop, idx)| if idx == 0 || idx % 2 == 1 { Some(stop) } else { None })
-                    .flat_map(|stop| {
-                        stop.clone()
-                            .activities
-                            .iter()
-                            .map(move |activity| (activity.clone(), context.get_activity_type(tour, stop, activity)))
+        intervals.iter().try_fold::<_, _, Result<_, TourViolation>>(Capacity::default(), |acc, interval| {
+            let (start_delivery, end_pickup) = interval
+                .iter()
+                .flat_map(|(_, (from, to))| once(from).chain(once(to)))
+                .zip(0..)
+                .filter_map(|(stop, idx)| if idx == 0 || idx % 2 == 1 { Some(stop) } else { None })
+                .flat_map(|stop| {
+                    stop.clone()
+                        .activities
+                        .iter()
+                        .map(move |activity| (activity.clone(), context.get_activity_type(tour, stop, activity)))
+                })
+                .try_fold::<_, _, Result<_, String>>((acc, Capacity::default()), |acc, (activity, activity_type)| {
+                    let activity_type = activity_type?;
+                    let demand = get_demand(context, &activity, &activity_type)?;
+                    Ok(match demand {
+                        (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
+                        (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
+                        (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand.clone(), acc.1 + demand),
+                        _ => acc,
                     })
-                    .try_fold::<_, _, Result<_, String>>(
-                        (acc, Capacity::default()),
-                        |acc, (activity, activity_type)| {
-                            let activity_type = activity_type?;
-                            let demand = get_demand(context, &activity, &activity_type)?;
-                            Ok(match demand {
-                                (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
-                                (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                                (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand.clone(), acc.1 + demand),
-                                _ => acc,
-                            })
-                        },
-                    )?;
+                })?;
 
-                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
+            let end_capacity =
+                interval.iter().try_fold::<_, _, Result<_, String>>(start_delivery, |acc, (idx, (from, to))| {
                     let from_load = Capacity::new(from.load.clone());
                     let to_load = Capacity::new(to.load.clone());
 
                     if from_load > capacity || to_load > capacity {
-                        return Err(format!("Load exceeds capacity in tour '{}'", tour.vehicle_id));
+                        let message = format!("Load exceeds capacity in tour '{}'", tour.vehicle_id);
+                        let stop_idx = if from_load > capacity { *idx } else { idx + 1 };
+                        violations.push(TourViolation::new(message, stop_idx, None));
                     }
 
                     let change = to.activities.iter().try_fold::<_, _, Result<_, String>>(
@@ -102,22 +100,25 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
                     let is_from_valid = from_load == acc;
                     let is_to_valid = to_load == from_load + change;
 
-                    if is_from_valid && is_to_valid {
-                        Ok(to_load)
-                    } else {
-                        let message = match (is_from_valid, is_to_valid) {
-                            (true, false) => format!("at stop {}", idx + 1),
-                            (false, true) => format!("at stop {}", idx),
-                            _ => format!("at stops {}, {}", idx, idx + 1),
+                    if !is_from_valid || !is_to_valid {
+                        let (message, stop_idx) = match (is_from_valid, is_to_valid) {
+                            (true, false) => (format!("at stop {}", idx + 1), idx + 1),
+                            (false, true) => (format!("at stop {}", idx), *idx),
+                            _ => (format!("at stops {}, {}", idx, idx + 1), *idx),
                         };
 
-                        Err(format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id))
+                        let message = format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id);
+                        violations.push(TourViolation::new(message, stop_idx, None));
                     }
+
+                    // NOTE reported load is used to continue checking, so a mismatch is reported once
+                    Ok(to_load)
                 })?;
 
-                Ok(end_capacity - end_pickup)
-            })
-            .map(|_| ())
+            Ok(end_capacity - end_pickup)
+        })?;
+
+        Ok(())
     })
 }
 
@@ -0,0 +1,43 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/limits_test.rs"]
+mod limits_test;
+
+use super::*;
+
+/// Checks that tour does not exceed vehicle's max distance and shift time limits.
+pub fn check_tour_limits(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
+        let vehicle = context.get_vehicle(tour.vehicle_id.as_str())?;
+        let limits = match vehicle.limits.as_ref() {
+            Some(limits) => limits,
+            None => return Ok(()),
+        };
+
+        let last = tour.stops.last().ok_or_else(|| format!("Tour '{}' has no stops", tour.vehicle_id))?;
+
+        if let Some(max_distance) = limits.max_distance {
+            if last.distance as f64 > max_distance {
+                violations.push(
+                    format!(
+                        "Tour '{}' exceeds max distance limit: {} > {}",
+                        tour.vehicle_id, last.distance, max_distance
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        if let Some(shift_time) = limits.shift_time {
+            // NOTE time is rounded to seconds in solution
+            let duration = parse_time(&last.time.arrival) - context.get_tour_departure(tour)?;
+            if duration > shift_time + 1. {
+                violations.push(
+                    format!("Tour '{}' exceeds shift time limit: {} > {}", tour.vehicle_id, duration, shift_time)
+                        .into(),
+                );
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -1,10 +1,17 @@
 //! This module provides functionality to automatically check that given solution is feasible
 //! which means that there is no constraint violations.
 
+#[cfg(test)]
+#[path = "../../tests/unit/checker/checker_test.rs"]
+mod checker_test;
+
+mod report;
+pub use self::report::*;
+
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::Location;
-use crate::parse_time;
+use crate::{format_time, parse_time};
 use std::collections::HashMap;
 use vrp_core::models::common::TimeWindow;
 
@@ -14,8 +21,46 @@ pub struct CheckerContext {
     pub matrices: Option<Vec<Matrix>>,
     pub solution: Solution,
     job_map: HashMap<String, Job>,
+    routing: Routing,
+}
+
+/// Specifies a violation found within a tour.
+struct TourViolation {
+    message: String,
+    stop_index: Option<usize>,
+    activity_index: Option<usize>,
+}
+
+impl TourViolation {
+    fn new(message: String, stop_index: usize, activity_index: Option<usize>) -> Self {
+        Self { message, stop_index: Some(stop_index), activity_index }
+    }
+}
+
+impl From<String> for TourViolation {
+    fn from(message: String) -> Self {
+        Self { message, stop_index: None, activity_index: None }
+    }
+}
+
+/// Runs tour check which adds found violations to the list. The check can be stopped earlier
+/// by returning violation as error, e.g. when tour cannot be checked at all.
+fn collect_violations<F>(check: F) -> Vec<TourViolation>
+where
+    F: FnOnce(&mut Vec<TourViolation>) -> Result<(), TourViolation>,
+{
+    let mut violations = vec![];
+
+    if let Err(violation) = check(&mut violations) {
+        violations.push(violation);
+    }
+
+    violations
 }
 
+type TourCheck = fn(&CheckerContext, &Tour) -> Vec<TourViolation>;
+type SolutionCheck = fn(&CheckerContext) -> Result<(), String>;
+
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
@@ -28,21 +73,75 @@ impl CheckerContext {
     pub fn new(problem: Problem, matrices: Option<Vec<Matrix>>, solution: Solution) -> Self {
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
 
-        Self { problem, matrices, solution, job_map }
+        let routing = Routing::new(&problem, matrices.as_ref());
+
+        Self { problem, matrices, solution, job_map, routing }
     }
 
+    /// Checks solution and returns the first hard constraint violation as error.
     pub fn check(&self) -> Result<(), String> {
-        check_vehicle_load(&self)?;
-        check_relations(&self)?;
-
-        if let Err(err) = check_breaks(&self) {
-            // TODO break is soft constraint and can be violated, how to improve checker?
-            println!("break is violated: {}", err);
-        }
+        self.create_report()
+            .violations
+            .into_iter()
+            .find(|violation| violation.severity == Severity::Hard)
+            .map_or(Ok(()), |violation| Err(violation.message))
+    }
 
-        check_assignment(&self)?;
+    /// Checks solution and returns report with all found violations. Tour specific rules are
+    /// checked for each tour separately and report every violating stop. Violation codes:
+    /// * C0001: vehicle load
+    /// * C0002: relations
+    /// * C0003: breaks (soft)
+    /// * C0004: job and vehicle assignment
+    /// * C0005: schedule, travel and statistic
+    /// * C0006: skills
+    /// * C0007: allowed areas
+    /// * C0008: max distance and shift time limits
+    /// * C0009: priorities
+    pub fn create_report(&self) -> CheckerReport {
+        let tour_checks: Vec<(&str, Severity, TourCheck)> = vec![
+            ("C0001", Severity::Hard, check_tour_load),
+            ("C0003", Severity::Soft, check_tour_breaks),
+            ("C0005", Severity::Hard, check_tour_routing),
+            ("C0006", Severity::Hard, check_tour_skills),
+            ("C0007", Severity::Hard, check_tour_areas),
+            ("C0008", Severity::Hard, check_tour_limits),
+            ("C0009", Severity::Hard, check_tour_priorities),
+        ];
+        let solution_checks: Vec<(&str, Severity, SolutionCheck)> = vec![
+            ("C0002", Severity::Hard, check_relations),
+            ("C0004", Severity::Hard, check_assignment),
+            ("C0005", Severity::Hard, check_routing_statistic),
+        ];
+
+        let tour_violations = tour_checks.into_iter().flat_map(|(code, severity, check)| {
+            self.solution.tours.iter().flat_map(move |tour| {
+                let severity = severity.clone();
+                check(self, tour).into_iter().map(move |violation| Violation {
+                    code: code.to_string(),
+                    severity: severity.clone(),
+                    vehicle_id: Some(tour.vehicle_id.clone()),
+                    shift_index: Some(tour.shift_index),
+                    stop_index: violation.stop_index,
+                    activity_index: violation.activity_index,
+                    message: violation.message,
+                })
+            })
+        });
+
+        let solution_violations = solution_checks.into_iter().filter_map(|(code, severity, check)| {
+            check(self).err().map(|message| Violation {
+                code: code.to_string(),
+                severity,
+                vehicle_id: None,
+                shift_index: None,
+                stop_index: None,
+                activity_index: None,
+                message,
+            })
+        });
 
-        Ok(())
+        CheckerReport { violations: tour_violations.chain(solution_violations).collect() }
     }
 
     /// Gets vehicle by its id.
@@ -70,6 +169,17 @@ impl CheckerContext {
         activity.location.clone().unwrap_or_else(|| stop.location.clone())
     }
 
+    /// Gets tour departure time in seconds since Unix epoch.
+    fn get_tour_departure(&self, tour: &Tour) -> Result<f64, String> {
+        let first = tour.stops.first().ok_or_else(|| format!("Tour '{}' has no stops", tour.vehicle_id))?;
+
+        // NOTE departure from the first stop is the arrival time of its next activity, if any
+        Ok(first.activities.get(1).map_or_else(
+            || parse_time(&first.time.departure),
+            |activity| self.get_activity_time(first, activity).start,
+        ))
+    }
+
     /// Gets vehicle shift where activity is used.
     fn get_vehicle_shift(&self, tour: &Tour) -> Result<VehicleShift, String> {
         let tour_time = TimeWindow::new(
@@ -220,17 +330,32 @@ fn get_location(stop: &Stop, activity: &Activity) -> Location {
 }
 
 fn same_locations(left: &Location, right: &Location) -> bool {
-    left.lat == right.lat && left.lng == right.lng
+    left == right
 }
 
 mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
-use crate::checker::capacity::check_vehicle_load;
+use crate::checker::capacity::check_tour_load;
 
 mod breaks;
-use crate::checker::breaks::check_breaks;
+use crate::checker::breaks::check_tour_breaks;
 
 mod relations;
 use crate::checker::relations::check_relations;
+
+mod routing;
+use crate::checker::routing::{check_routing_statistic, check_tour_routing, Routing};
+
+mod skills;
+use crate::checker::skills::check_tour_skills;
+
+mod areas;
+use crate::checker::areas::check_tour_areas;
+
+mod limits;
+use crate::checker::limits::check_tour_limits;
+
+mod priorities;
+use crate::checker::priorities::check_tour_priorities;
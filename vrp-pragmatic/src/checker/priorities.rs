@@ -0,0 +1,35 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/priorities_test.rs"]
+mod priorities_test;
+
+use super::*;
+
+/// Checks that jobs with higher priority are served before jobs with lower priority within a tour.
+pub fn check_tour_priorities(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    tour.stops
+        .iter()
+        .enumerate()
+        .flat_map(|(stop_idx, stop)| {
+            stop.activities.iter().enumerate().map(move |(activity_idx, activity)| (stop_idx, activity_idx, activity))
+        })
+        .filter_map(|(stop_idx, activity_idx, activity)| {
+            context
+                .get_job_by_id(activity.job_id.as_str())
+                .map(|job| (stop_idx, activity_idx, job.id.as_str(), job.priority.unwrap_or(1)))
+        })
+        .fold(
+            (None, vec![]),
+            |(prev, mut violations): (Option<(&str, i32)>, Vec<_>), (stop_idx, activity_idx, job_id, priority)| {
+                if let Some((prev_id, prev_priority)) = prev.filter(|(_, prev_priority)| *prev_priority > priority) {
+                    let message = format!(
+                        "Job '{}' with priority {} is served after job '{}' with priority {} in tour '{}'",
+                        job_id, priority, prev_id, prev_priority, tour.vehicle_id
+                    );
+                    violations.push(TourViolation::new(message, stop_idx, Some(activity_idx)));
+                }
+
+                (Some((job_id, priority)), violations)
+            },
+        )
+        .1
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Error;
+use std::io::{BufWriter, Write};
+
+/// Specifies violation severity.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// A hard constraint is violated: solution is not feasible.
+    Hard,
+    /// A soft constraint is violated: solution is feasible, but its quality can be affected.
+    Soft,
+}
+
+/// Represents a single constraint violation found by checker.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    /// A violation code.
+    pub code: String,
+    /// A violation severity.
+    pub severity: Severity,
+    /// A vehicle id of the tour where violation is found.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub vehicle_id: Option<String>,
+    /// A shift index of the tour where violation is found.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub shift_index: Option<usize>,
+    /// An index of the stop in the tour where violation is found.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stop_index: Option<usize>,
+    /// An index of the activity in the stop where violation is found.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub activity_index: Option<usize>,
+    /// A violation description.
+    pub message: String,
+}
+
+/// Represents a list of all violations found by checker.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug, Default)]
+pub struct CheckerReport {
+    /// Found violations.
+    pub violations: Vec<Violation>,
+}
+
+impl CheckerReport {
+    /// Returns true if there are no hard constraint violations.
+    pub fn is_feasible(&self) -> bool {
+        self.violations.iter().all(|violation| violation.severity != Severity::Hard)
+    }
+}
+
+/// Serializes checker report in json format.
+pub fn serialize_report<W: Write>(writer: BufWriter<W>, report: &CheckerReport) -> Result<(), Error> {
+    serde_json::to_writer_pretty(writer, report)
+}
@@ -0,0 +1,299 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/routing_test.rs"]
+mod routing_test;
+
+use super::*;
+use crate::format::problem::create_approx_matrices;
+use crate::format::CoordIndex;
+
+/// A tolerance used to compare times as they are rounded to seconds in solution.
+const TIME_TOLERANCE: f64 = 1.;
+
+/// Checks that solution statistic is a sum of tour statistics.
+pub fn check_routing_statistic(context: &CheckerContext) -> Result<(), String> {
+    let statistic = context.solution.tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+
+    check_statistic("solution", &statistic, &context.solution.statistic, 0)
+}
+
+/// Provides access to routing matrices.
+pub(super) struct Routing {
+    coord_index: CoordIndex,
+    matrices: Vec<Matrix>,
+}
+
+impl Routing {
+    /// Creates a new instance of `Routing` using approximated matrices when routing matrices are not specified.
+    pub(super) fn new(problem: &Problem, matrices: Option<&Vec<Matrix>>) -> Self {
+        let matrices = matrices.cloned().unwrap_or_else(|| create_approx_matrices(problem));
+
+        Self { coord_index: CoordIndex::new(problem), matrices }
+    }
+
+    /// Returns vehicle's travel duration and distance between two locations.
+    fn get_travel(&self, vehicle: &VehicleType, from: &Location, to: &Location) -> Result<(f64, i32), String> {
+        let profile = vehicle.profile.as_str();
+        let matrix = self
+            .matrices
+            .iter()
+            .find(|matrix| matrix.profile == profile)
+            .ok_or_else(|| format!("Cannot find matrix for profile '{}'", profile))?;
+
+        let get_index = |location: &Location| {
+            self.coord_index.get_by_loc(location).ok_or_else(|| format!("Cannot find location '{:?}'", location))
+        };
+
+        let size = (matrix.travel_times.len() as f64).sqrt().round() as usize;
+        let index = get_index(from)? * size + get_index(to)?;
+
+        let is_unreachable =
+            matrix.error_codes.as_ref().and_then(|codes| codes.get(index)).map_or(false, |code| *code > 0);
+        match (matrix.travel_times.get(index), matrix.distances.get(index)) {
+            (Some(duration), Some(distance)) if !is_unreachable => {
+                Ok(((*duration as f64 / vehicle.speed_factor.unwrap_or(1.)).round(), *distance as i32))
+            }
+            _ => Err(format!("Cannot get routing data from '{:?}' to '{:?}'", from, to)),
+        }
+    }
+}
+
+/// Checks that tour schedule, traveled distances, time windows, shift time and statistic are
+/// consistent with routing data.
+pub fn check_tour_routing(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
+        let routing = &context.routing;
+        let vehicle = context.get_vehicle(tour.vehicle_id.as_str())?;
+        let shift = context.get_vehicle_shift(tour)?;
+
+        let first = tour.stops.first().ok_or_else(|| format!("Tour '{}' has no stops", tour.vehicle_id))?;
+        let last = tour.stops.last().unwrap();
+
+        if parse_time(&first.time.arrival) + TIME_TOLERANCE < parse_time(&shift.start.time) {
+            violations.push(TourViolation::new(
+                format!("Tour '{}' starts before shift start", tour.vehicle_id),
+                0,
+                None,
+            ));
+        }
+
+        if let Some(end) = shift.end.as_ref() {
+            if parse_time(&last.time.arrival) > parse_time(&end.time) + TIME_TOLERANCE {
+                let message = format!("Tour '{}' ends after shift end", tour.vehicle_id);
+                violations.push(TourViolation::new(message, tour.stops.len() - 1, None));
+            }
+        }
+
+        let start_departure = context.get_tour_departure(tour)?;
+
+        let mut statistic = Statistic::default();
+        let mut current = (first.location.clone(), start_departure);
+        let mut activity_count = 0;
+        // NOTE distance is compared using the previous stop's reported distance, so a mismatch is reported once
+        let mut reported_distance = 0;
+
+        for (stop_idx, stop) in tour.stops.iter().enumerate() {
+            for (activity_idx, activity) in stop.activities.iter().enumerate() {
+                if activity.activity_type == "departure" {
+                    continue;
+                }
+
+                let violation = |message: String| TourViolation::new(message, stop_idx, Some(activity_idx));
+
+                let location = context.get_activity_location(stop, activity);
+                let time = context.get_activity_time(stop, activity);
+                let (duration, distance) = routing.get_travel(vehicle, &current.0, &location).map_err(violation)?;
+                let arrival = current.1 + duration;
+
+                statistic.distance += distance;
+                statistic.times.driving += duration as i32;
+
+                if activity_idx == 0 {
+                    if let Err(message) = check_time(tour, stop_idx, "arrival", arrival, parse_time(&stop.time.arrival))
+                    {
+                        violations.push(violation(message));
+                    }
+
+                    let expected_distance = reported_distance + distance;
+                    if expected_distance != stop.distance {
+                        violations.push(violation(format!(
+                            "Distance mismatch at stop {} in tour '{}': expected {}, got {}",
+                            stop_idx, tour.vehicle_id, expected_distance, stop.distance
+                        )));
+                    }
+                    reported_distance = stop.distance;
+                }
+
+                if let Err(message) = check_time(tour, stop_idx, "activity start", arrival, time.start) {
+                    violations.push(violation(message));
+                }
+
+                // NOTE parking time is spent once per stop before its first activity
+                let parking = if activity_idx == 0 && activity.activity_type != "arrival" {
+                    vehicle.parking.unwrap_or(0.)
+                } else {
+                    0.
+                };
+                statistic.times.parking += parking as i32;
+
+                // NOTE reported duration is used to continue checking when activity schedule is invalid
+                let (service, is_break) = check_activity_schedule(context, tour, stop, activity, &time, parking)
+                    .unwrap_or_else(|message| {
+                        violations.push(violation(message));
+                        ((time.end - time.start - parking).max(0.), activity.activity_type == "break")
+                    });
+                if is_break {
+                    statistic.times.break_time += service as i32;
+                } else {
+                    statistic.times.serving += service as i32;
+                }
+
+                activity_count += 1;
+                current = (location, time.end);
+            }
+
+            if let Err(message) = check_time(tour, stop_idx, "departure", current.1, parse_time(&stop.time.departure)) {
+                violations.push(TourViolation::new(message, stop_idx, None));
+            }
+        }
+
+        let costs = &vehicle.costs;
+
+        statistic.duration = (parse_time(&last.time.departure) - start_departure) as i32;
+        statistic.times.waiting = statistic.duration
+            - statistic.times.driving
+            - statistic.times.serving
+            - statistic.times.break_time
+            - statistic.times.parking;
+        statistic.cost = costs.fixed.unwrap_or(0.)
+            + costs.distance * statistic.distance as f64
+            + costs.time * statistic.times.driving as f64
+            + costs.waiting.unwrap_or(costs.time) * statistic.times.waiting as f64
+            + costs.service.unwrap_or(costs.time)
+                * (statistic.times.serving + statistic.times.break_time + statistic.times.parking) as f64;
+
+        check_statistic(format!("tour '{}'", tour.vehicle_id).as_str(), &statistic, &tour.statistic, activity_count)
+            .map_err(TourViolation::from)
+    })
+}
+
+/// Checks that activity is performed within one of its time windows and takes expected time
+/// including parking time. Returns activity duration and whether it is a break.
+fn check_activity_schedule(
+    context: &CheckerContext,
+    tour: &Tour,
+    stop: &Stop,
+    activity: &Activity,
+    time: &TimeWindow,
+    parking: f64,
+) -> Result<(f64, bool), String> {
+    let parse_times = |times: &Option<Vec<Vec<String>>>| {
+        times.as_ref().map_or_else(|| vec![TimeWindow::max()], |times| times.iter().map(parse_time_window).collect())
+    };
+
+    let location = context.get_activity_location(stop, activity);
+    let activity_type = context.get_activity_type(tour, stop, activity)?;
+    let service_factor = context.get_vehicle(tour.vehicle_id.as_str())?.service_factor.unwrap_or(1.);
+
+    // NOTE break time window is a soft constraint which is checked separately
+    let (candidates, is_break) = match &activity_type {
+        ActivityType::Terminal => (vec![(0., vec![TimeWindow::max()])], false),
+        ActivityType::Break(vehicle_break) => (vec![(vehicle_break.duration, vec![TimeWindow::max()])], true),
+        ActivityType::Reload(reload) => (vec![(reload.duration, parse_times(&reload.times))], false),
+        ActivityType::Job(_) => (
+            context.visit_job(
+                activity,
+                &activity_type,
+                |_, task| {
+                    task.places
+                        .iter()
+                        .filter(|place| same_locations(&place.location, &location))
+                        .map(|place| (place.duration * service_factor, parse_times(&place.times)))
+                        .collect::<Vec<_>>()
+                },
+                Vec::new,
+            )?,
+            false,
+        ),
+    };
+
+    candidates
+        .iter()
+        .find(|(duration, times)| {
+            times.iter().any(|tw| {
+                let start = (time.start + parking).max(tw.start);
+                start <= tw.end && (start + duration - time.end).abs() <= TIME_TOLERANCE
+            })
+        })
+        .map(|(duration, _)| (*duration, is_break))
+        .ok_or_else(|| {
+            format!(
+                "Activity '{}' of '{}' in tour '{}' violates time window or duration",
+                activity.activity_type, activity.job_id, tour.vehicle_id
+            )
+        })
+}
+
+fn check_time(tour: &Tour, stop_idx: usize, name: &str, expected: f64, actual: f64) -> Result<(), String> {
+    if (expected - actual).abs() > TIME_TOLERANCE {
+        Err(format!(
+            "Schedule mismatch at stop {} in tour '{}': expected {} time {}, got {}",
+            stop_idx,
+            tour.vehicle_id,
+            name,
+            format_time(expected),
+            format_time(actual)
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Compares statistics. Waiting time and cost can differ slightly as service times are rounded
+/// for each activity.
+fn check_statistic(name: &str, expected: &Statistic, actual: &Statistic, activity_count: usize) -> Result<(), String> {
+    let mismatch = |field: &str, expected: String, actual: String| {
+        Err(format!("Statistic mismatch for {}: expected {} {}, got {}", name, field, expected, actual))
+    };
+
+    let check_value = |field: &str, expected: i32, actual: i32, tolerance: i32| {
+        if (expected - actual).abs() > tolerance {
+            mismatch(field, expected.to_string(), actual.to_string())
+        } else {
+            Ok(())
+        }
+    };
+
+    let tolerance = activity_count as i32;
+
+    check_value("distance", expected.distance, actual.distance, 0)?;
+    check_value("duration", expected.duration, actual.duration, 0)?;
+    check_value("driving", expected.times.driving, actual.times.driving, 0)?;
+    check_value("serving", expected.times.serving, actual.times.serving, 0)?;
+    check_value("break", expected.times.break_time, actual.times.break_time, 0)?;
+    check_value("parking", expected.times.parking, actual.times.parking, 0)?;
+    check_value("waiting", expected.times.waiting, actual.times.waiting, tolerance)?;
+
+    let is_cost_mismatch = |expected: f64, actual: f64| (expected - actual).abs() > (expected.abs() * 1E-6).max(1.);
+
+    if is_cost_mismatch(expected.cost, actual.cost) {
+        return mismatch("cost", expected.cost.to_string(), actual.cost.to_string());
+    }
+
+    if let Some(costs) = actual.costs.as_ref() {
+        let total = costs.fixed + costs.distance + costs.driving + costs.waiting + costs.serving;
+        if is_cost_mismatch(actual.cost, total) {
+            return mismatch("cost breakdown", actual.cost.to_string(), total.to_string());
+        }
+    }
+
+    let cost_objective = actual.objectives.as_ref().and_then(|objectives| {
+        objectives.secondary.iter().chain(objectives.primary.iter()).find(|o| o.name == "minimize-cost")
+    });
+    if let Some(objective) = cost_objective {
+        if is_cost_mismatch(actual.cost, objective.value) {
+            return mismatch("cost objective", actual.cost.to_string(), objective.value.to_string());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/skills_test.rs"]
+mod skills_test;
+
+use super::*;
+use std::collections::HashSet;
+
+/// Checks that jobs are served only by vehicles which have all required skills.
+pub fn check_tour_skills(context: &CheckerContext, tour: &Tour) -> Vec<TourViolation> {
+    collect_violations(|violations| {
+        let vehicle = context.get_vehicle(tour.vehicle_id.as_str())?;
+        let skills = vehicle.skills.as_ref().map_or_else(HashSet::new, |skills| skills.iter().collect());
+
+        tour.stops.iter().enumerate().for_each(|(stop_idx, stop)| {
+            stop.activities.iter().enumerate().for_each(|(activity_idx, activity)| {
+                let required = context.get_job_by_id(activity.job_id.as_str()).and_then(|job| job.skills.as_ref());
+
+                if let Some(required) = required.filter(|required| !required.iter().all(|skill| skills.contains(skill)))
+                {
+                    let message = format!(
+                        "Job '{}' requires skills '{:?}' which vehicle '{}' does not have",
+                        activity.job_id, required, tour.vehicle_id
+                    );
+                    violations.push(TourViolation::new(message, stop_idx, Some(activity_idx)));
+                }
+            })
+        });
+
+        Ok(())
+    })
+}
@@ -0,0 +1,205 @@
+//! This module provides functionality to compare two solutions of the same problem and report
+//! what has changed for drivers: moved jobs, changed order, shifted arrivals and assignment changes.
+
+#[cfg(test)]
+#[path = "../../tests/unit/diff/diff_test.rs"]
+mod diff_test;
+
+mod report;
+pub use self::report::*;
+
+use crate::format::solution::{Solution, Tour};
+use crate::parse_time;
+use std::collections::{HashMap, HashSet};
+
+/// Represents a job activity visited in a tour.
+struct JobVisit<'a> {
+    job_id: &'a str,
+    activity_type: &'a str,
+    arrival: &'a String,
+    vehicle_id: &'a str,
+}
+
+fn is_job_activity(activity_type: &str) -> bool {
+    match activity_type {
+        "departure" | "arrival" | "break" | "reload" => false,
+        _ => true,
+    }
+}
+
+fn get_job_visits(tour: &Tour) -> Vec<JobVisit> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| {
+            stop.activities.iter().filter(|activity| is_job_activity(activity.activity_type.as_str())).map(
+                move |activity| JobVisit {
+                    job_id: activity.job_id.as_str(),
+                    activity_type: activity.activity_type.as_str(),
+                    arrival: activity.time.as_ref().map_or(&stop.time.arrival, |time| &time.start),
+                    vehicle_id: tour.vehicle_id.as_str(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Returns job visits keyed by job id and occurrence index of the job in its tour.
+fn get_visits_by_key(solution: &Solution) -> HashMap<(&str, usize), JobVisit> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let mut occurrences = HashMap::<&str, usize>::new();
+            get_job_visits(tour)
+                .into_iter()
+                .map(|visit| {
+                    let occurrence = occurrences.entry(visit.job_id).or_insert(0);
+                    *occurrence += 1;
+                    ((visit.job_id, *occurrence - 1), visit)
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+        })
+        .collect()
+}
+
+fn get_job_vehicles(solution: &Solution) -> HashMap<&str, &str> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| get_job_visits(tour).into_iter())
+        .map(|visit| (visit.job_id, visit.vehicle_id))
+        .collect()
+}
+
+fn get_moved_jobs(old: &Solution, new: &Solution) -> Vec<MovedJob> {
+    let old_vehicles = get_job_vehicles(old);
+    let mut moved_jobs = get_job_vehicles(new)
+        .into_iter()
+        .filter_map(|(job_id, to_vehicle_id)| {
+            old_vehicles.get(job_id).filter(|&&from_vehicle_id| from_vehicle_id != to_vehicle_id).map(
+                |from_vehicle_id| MovedJob {
+                    job_id: job_id.to_string(),
+                    from_vehicle_id: from_vehicle_id.to_string(),
+                    to_vehicle_id: to_vehicle_id.to_string(),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    moved_jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    moved_jobs
+}
+
+fn get_order_changes(old: &Solution, new: &Solution) -> Vec<TourOrderChange> {
+    let get_order = |tour: &Tour| get_job_visits(tour).iter().map(|visit| visit.job_id.to_string()).collect::<Vec<_>>();
+
+    new.tours
+        .iter()
+        .filter_map(|new_tour| {
+            old.tours
+                .iter()
+                .find(|old_tour| {
+                    old_tour.vehicle_id == new_tour.vehicle_id && old_tour.shift_index == new_tour.shift_index
+                })
+                .map(|old_tour| (get_order(old_tour), get_order(new_tour), new_tour))
+        })
+        .filter(|(old_order, new_order, _)| {
+            let old_jobs = old_order.iter().collect::<HashSet<_>>();
+            let new_jobs = new_order.iter().collect::<HashSet<_>>();
+
+            let old_common = old_order.iter().filter(|job_id| new_jobs.contains(job_id));
+            let new_common = new_order.iter().filter(|job_id| old_jobs.contains(job_id));
+
+            !old_common.eq(new_common)
+        })
+        .map(|(old_order, new_order, tour)| TourOrderChange {
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: tour.shift_index,
+            old_order,
+            new_order,
+        })
+        .collect()
+}
+
+fn get_arrival_shifts(old: &Solution, new: &Solution, threshold: i64) -> Vec<ArrivalShift> {
+    let old_visits = get_visits_by_key(old);
+    let mut shifts = get_visits_by_key(new)
+        .into_iter()
+        .filter_map(|(key, new_visit)| {
+            old_visits.get(&key).and_then(|old_visit| {
+                let shift = (parse_time(new_visit.arrival) - parse_time(old_visit.arrival)) as i64;
+
+                if shift.abs() > threshold {
+                    Some((
+                        key.1,
+                        ArrivalShift {
+                            job_id: new_visit.job_id.to_string(),
+                            activity_type: new_visit.activity_type.to_string(),
+                            vehicle_id: new_visit.vehicle_id.to_string(),
+                            old_arrival: old_visit.arrival.clone(),
+                            new_arrival: new_visit.arrival.clone(),
+                            shift,
+                        },
+                    ))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    shifts.sort_by(|(a_idx, a), (b_idx, b)| a.job_id.cmp(&b.job_id).then(a_idx.cmp(b_idx)));
+
+    shifts.into_iter().map(|(_, shift)| shift).collect()
+}
+
+fn get_assignment_changes(old: &Solution, new: &Solution) -> (Vec<String>, Vec<String>) {
+    let get_unassigned =
+        |solution: &Solution| solution.unassigned.iter().map(|job| job.job_id.clone()).collect::<HashSet<_>>();
+
+    let old_unassigned = get_unassigned(old);
+    let new_unassigned = get_unassigned(new);
+
+    let get_sorted = |jobs: HashSet<&String>| {
+        let mut jobs = jobs.into_iter().cloned().collect::<Vec<_>>();
+        jobs.sort();
+        jobs
+    };
+
+    (
+        get_sorted(new_unassigned.difference(&old_unassigned).collect()),
+        get_sorted(old_unassigned.difference(&new_unassigned).collect()),
+    )
+}
+
+fn get_statistic_delta(old: &Solution, new: &Solution) -> StatisticDelta {
+    let (old_stat, new_stat) = (&old.statistic, &new.statistic);
+
+    StatisticDelta {
+        cost: new_stat.cost - old_stat.cost,
+        distance: new_stat.distance - old_stat.distance,
+        duration: new_stat.duration - old_stat.duration,
+        driving: new_stat.times.driving - old_stat.times.driving,
+        serving: new_stat.times.serving - old_stat.times.serving,
+        waiting: new_stat.times.waiting - old_stat.times.waiting,
+        break_time: new_stat.times.break_time - old_stat.times.break_time,
+        tours: new.tours.len() as i32 - old.tours.len() as i32,
+    }
+}
+
+/// Compares two solutions of the same problem. Arrival time shifts are reported only when
+/// their absolute value, in seconds, is greater than given threshold.
+pub fn diff_solutions(old: &Solution, new: &Solution, arrival_threshold: i64) -> SolutionDiff {
+    let (newly_unassigned, newly_assigned) = get_assignment_changes(old, new);
+
+    SolutionDiff {
+        moved_jobs: get_moved_jobs(old, new),
+        order_changes: get_order_changes(old, new),
+        arrival_shifts: get_arrival_shifts(old, new, arrival_threshold),
+        newly_unassigned,
+        newly_assigned,
+        statistic: get_statistic_delta(old, new),
+    }
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use std::io::{BufWriter, Write};
+
+/// Represents a job which is served by another vehicle in new solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MovedJob {
+    /// Job id.
+    pub job_id: String,
+    /// Vehicle id in old solution.
+    pub from_vehicle_id: String,
+    /// Vehicle id in new solution.
+    pub to_vehicle_id: String,
+}
+
+/// Represents a tour where order of jobs, served in both solutions, is changed.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourOrderChange {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Job ids in the order of visiting in old solution.
+    pub old_order: Vec<String>,
+    /// Job ids in the order of visiting in new solution.
+    pub new_order: Vec<String>,
+}
+
+/// Represents a job activity which arrival time is shifted beyond threshold.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArrivalShift {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    pub activity_type: String,
+    /// Vehicle id in new solution.
+    pub vehicle_id: String,
+    /// Arrival time in old solution.
+    pub old_arrival: String,
+    /// Arrival time in new solution.
+    pub new_arrival: String,
+    /// Difference in seconds between new and old arrival.
+    pub shift: i64,
+}
+
+/// Represents difference between statistics of new and old solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticDelta {
+    /// Total cost delta.
+    pub cost: f64,
+    /// Total distance delta.
+    pub distance: i32,
+    /// Total duration delta.
+    pub duration: i32,
+    /// Driving time delta.
+    pub driving: i32,
+    /// Serving time delta.
+    pub serving: i32,
+    /// Waiting time delta.
+    pub waiting: i32,
+    /// Break time delta.
+    #[serde(rename(serialize = "break", deserialize = "break"))]
+    pub break_time: i32,
+    /// Amount of tours delta.
+    pub tours: i32,
+}
+
+/// Represents all changes between old and new solutions of the same problem.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Jobs served by another vehicle.
+    pub moved_jobs: Vec<MovedJob>,
+    /// Tours with changed order of jobs.
+    pub order_changes: Vec<TourOrderChange>,
+    /// Job activities with arrival time shifted beyond threshold.
+    pub arrival_shifts: Vec<ArrivalShift>,
+    /// Jobs which were assigned in old solution, but are unassigned in new one.
+    pub newly_unassigned: Vec<String>,
+    /// Jobs which were unassigned in old solution, but are assigned in new one.
+    pub newly_assigned: Vec<String>,
+    /// Statistic delta.
+    pub statistic: StatisticDelta,
+}
+
+impl SolutionDiff {
+    /// Returns true if there are no changes in job assignments, order and arrival times.
+    pub fn is_empty(&self) -> bool {
+        self.moved_jobs.is_empty()
+            && self.order_changes.is_empty()
+            && self.arrival_shifts.is_empty()
+            && self.newly_unassigned.is_empty()
+            && self.newly_assigned.is_empty()
+    }
+}
+
+/// Serializes solution diff in json format.
+pub fn serialize_diff<W: Write>(writer: BufWriter<W>, diff: &SolutionDiff) -> Result<(), serde_json::Error> {
+    serde_json::to_writer_pretty(writer, diff)
+}
+
+/// Writes solution diff in human readable text format.
+pub fn serialize_diff_as_text<W: Write>(writer: BufWriter<W>, diff: &SolutionDiff) -> Result<(), std::io::Error> {
+    let mut writer = writer;
+    let statistic = &diff.statistic;
+
+    writeln!(
+        writer,
+        "statistic: cost {:+.2}, distance {:+}, duration {:+}, tours {:+}",
+        statistic.cost, statistic.distance, statistic.duration, statistic.tours
+    )?;
+    writeln!(
+        writer,
+        "times: driving {:+}, serving {:+}, waiting {:+}, break {:+}",
+        statistic.driving, statistic.serving, statistic.waiting, statistic.break_time
+    )?;
+
+    if diff.is_empty() {
+        writeln!(writer, "no changes in job assignments")?;
+    }
+
+    diff.moved_jobs.iter().try_for_each(|moved| {
+        writeln!(writer, "moved: job '{}' from '{}' to '{}'", moved.job_id, moved.from_vehicle_id, moved.to_vehicle_id)
+    })?;
+
+    diff.order_changes.iter().try_for_each(|change| {
+        writeln!(
+            writer,
+            "reordered: vehicle '{}' shift {}: [{}] -> [{}]",
+            change.vehicle_id,
+            change.shift_index,
+            change.old_order.join(", "),
+            change.new_order.join(", ")
+        )
+    })?;
+
+    diff.arrival_shifts.iter().try_for_each(|shift| {
+        writeln!(
+            writer,
+            "shifted: job '{}' ({}) on '{}' by {:+}s: {} -> {}",
+            shift.job_id, shift.activity_type, shift.vehicle_id, shift.shift, shift.old_arrival, shift.new_arrival
+        )
+    })?;
+
+    diff.newly_unassigned.iter().try_for_each(|job_id| writeln!(writer, "unassigned: job '{}'", job_id))?;
+    diff.newly_assigned.iter().try_for_each(|job_id| writeln!(writer, "assigned: job '{}'", job_id))?;
+
+    writer.flush()
+}
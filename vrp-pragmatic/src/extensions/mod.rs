@@ -6,5 +6,8 @@ pub use self::multi_dim_capacity::MultiDimensionalCapacity;
 mod only_vehicle_activity_cost;
 pub use self::only_vehicle_activity_cost::OnlyVehicleActivityCost;
 
+mod speed_factor_transport_cost;
+pub use self::speed_factor_transport_cost::SpeedFactorTransportCost;
+
 mod typed_actor_group_key;
 pub use self::typed_actor_group_key::*;
@@ -1,4 +1,4 @@
-use vrp_core::models::common::{Cost, Timestamp};
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
 use vrp_core::models::problem::{ActivityCost, Actor};
 use vrp_core::models::solution::Activity;
 
@@ -12,6 +12,24 @@ impl ActivityCost for OnlyVehicleActivityCost {
 
         waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
     }
+
+    fn duration(&self, actor: &Actor, activity: &Activity, _arrival: Timestamp) -> Duration {
+        let activity_type = activity.job.as_ref().and_then(|job| job.dimens.get_value::<String>("type"));
+        let factor = match activity_type.map(|activity_type| activity_type.as_str()) {
+            None | Some("break") | Some("reload") => 1.,
+            _ => actor.vehicle.dimens.get_value::<f64>("service_factor").cloned().unwrap_or(1.),
+        };
+
+        activity.place.duration * factor
+    }
+
+    fn parking(&self, actor: &Actor, activity: &Activity) -> Duration {
+        if activity.job.is_some() {
+            actor.vehicle.dimens.get_value::<f64>("parking").cloned().unwrap_or(0.)
+        } else {
+            0.
+        }
+    }
 }
 
 impl Default for OnlyVehicleActivityCost {
@@ -0,0 +1,52 @@
+#[cfg(test)]
+#[path = "../../tests/unit/extensions/speed_factor_transport_cost_test.rs"]
+mod speed_factor_transport_cost_test;
+
+use std::sync::Arc;
+use vrp_core::models::common::{Distance, Duration, Location, Profile, Timestamp};
+use vrp_core::models::problem::TransportCost;
+
+/// Applies vehicle speed factors on top of routing matrices. Each vehicle type with speed factor
+/// has its own virtual profile which refers to the routing matrix of its original profile, so
+/// matrices are shared between vehicle types.
+pub struct SpeedFactorTransportCost {
+    inner: Arc<dyn TransportCost + Send + Sync>,
+    base_profiles: usize,
+    virtual_profiles: Vec<(Profile, f64)>,
+}
+
+impl SpeedFactorTransportCost {
+    /// Creates a new instance of `SpeedFactorTransportCost`. Virtual profiles are specified as pairs of
+    /// original profile and speed factor, their indices start after amount of original profiles.
+    pub fn new(
+        inner: Arc<dyn TransportCost + Send + Sync>,
+        base_profiles: usize,
+        virtual_profiles: Vec<(Profile, f64)>,
+    ) -> Self {
+        Self { inner, base_profiles, virtual_profiles }
+    }
+
+    fn get_profile(&self, profile: Profile) -> (Profile, Option<f64>) {
+        (profile as usize)
+            .checked_sub(self.base_profiles)
+            .and_then(|idx| self.virtual_profiles.get(idx))
+            .map_or((profile, None), |&(profile, speed_factor)| (profile, Some(speed_factor)))
+    }
+}
+
+impl TransportCost for SpeedFactorTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Duration {
+        let (profile, speed_factor) = self.get_profile(profile);
+        let duration = self.inner.duration(profile, from, to, departure);
+
+        match speed_factor {
+            // NOTE negative duration marks unreachable location
+            Some(speed_factor) if duration >= 0. => (duration / speed_factor).round(),
+            _ => duration,
+        }
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, departure: Timestamp) -> Distance {
+        self.inner.distance(self.get_profile(profile).0, from, to, departure)
+    }
+}
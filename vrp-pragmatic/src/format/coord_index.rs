@@ -1,4 +1,4 @@
-//! A helper module for processing geo coordinates in problem and solution.
+//! A helper module for processing locations in problem and solution.
 
 use crate::format::problem::Problem;
 use crate::format::Location;
@@ -6,7 +6,8 @@ use std::cmp::Ordering::Less;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-/// A helper struct which keeps track of coordinate mapping.
+/// A helper struct which keeps track of location mapping. Locations represented by index are
+/// mapped directly to that index, coordinates are mapped in the order of their appearance.
 pub struct CoordIndex {
     direct_index: HashMap<Location, usize>,
     reverse_index: HashMap<usize, Location>,
@@ -57,7 +58,7 @@ impl CoordIndex {
 
     pub fn add(&mut self, location: &Location) {
         if self.direct_index.get(location).is_none() {
-            let value = self.direct_index.len();
+            let value = location.as_index().unwrap_or_else(|| self.direct_index.len());
             self.direct_index.insert(location.clone(), value);
             self.reverse_index.insert(value, location.clone());
         }
@@ -71,6 +72,16 @@ impl CoordIndex {
         self.reverse_index.get(index).cloned()
     }
 
+    /// Checks whether any location is represented by index.
+    pub fn has_indices(&self) -> bool {
+        self.direct_index.keys().any(|location| location.as_index().is_some())
+    }
+
+    /// Checks whether any location is represented by coordinate.
+    pub fn has_coordinates(&self) -> bool {
+        self.direct_index.keys().any(|location| location.as_index().is_none())
+    }
+
     pub fn unique(&self) -> Vec<Location> {
         let mut sorted_pairs: Vec<_> = self.reverse_index.iter().collect();
         sorted_pairs.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Less));
@@ -82,14 +93,29 @@ impl Eq for Location {}
 
 impl PartialEq for Location {
     fn eq(&self, other: &Self) -> bool {
-        self.lat == other.lat && self.lng == other.lng
+        match (self, other) {
+            (Location::Coordinate { lat: l_lat, lng: l_lng }, Location::Coordinate { lat: r_lat, lng: r_lng }) => {
+                l_lat == r_lat && l_lng == r_lng
+            }
+            (Location::Reference { index: left }, Location::Reference { index: right }) => left == right,
+            _ => false,
+        }
     }
 }
 
 impl Hash for Location {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        write_hash(self.lat, state);
-        write_hash(self.lng, state);
+        match self {
+            Location::Coordinate { lat, lng } => {
+                state.write_u8(0);
+                write_hash(*lat, state);
+                write_hash(*lng, state);
+            }
+            Location::Reference { index } => {
+                state.write_u8(1);
+                state.write_usize(*index);
+            }
+        }
     }
 }
 
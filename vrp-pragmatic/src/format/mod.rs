@@ -6,19 +6,49 @@ extern crate serde_json;
 use serde::{Deserialize, Serialize};
 use std::io::BufWriter;
 
-/// A location type represented by latitude and longitude.
+/// A location type represented by latitude and longitude or by index in routing matrix.
 #[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct Location {
-    /// Latitude.
-    pub lat: f64,
-    /// Longitude.
-    pub lng: f64,
+#[serde(untagged)]
+pub enum Location {
+    /// A location represented by geocoordinate.
+    Coordinate {
+        /// Latitude.
+        lat: f64,
+        /// Longitude.
+        lng: f64,
+    },
+    /// A location represented by index in routing matrix.
+    Reference {
+        /// An index in routing matrix.
+        index: usize,
+    },
 }
 
 impl Location {
-    /// Creates new `[Location]`.
+    /// Creates new `[Location]` from latitude and longitude.
     pub fn new(lat: f64, lng: f64) -> Self {
-        Self { lat, lng }
+        Self::Coordinate { lat, lng }
+    }
+
+    /// Creates new `[Location]` from index in routing matrix.
+    pub fn new_reference(index: usize) -> Self {
+        Self::Reference { index }
+    }
+
+    /// Returns latitude and longitude. Panics if location is represented by index.
+    pub fn to_lat_lng(&self) -> (f64, f64) {
+        match self {
+            Self::Coordinate { lat, lng } => (*lat, *lng),
+            Self::Reference { index } => panic!("expected coordinate, got index: {}", index),
+        }
+    }
+
+    /// Returns index in routing matrix if location is represented by index.
+    pub fn as_index(&self) -> Option<usize> {
+        match self {
+            Self::Coordinate { .. } => None,
+            Self::Reference { index } => Some(*index),
+        }
     }
 }
 
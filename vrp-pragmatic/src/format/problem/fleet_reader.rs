@@ -1,4 +1,4 @@
-use crate::extensions::{create_typed_actor_groups, MultiDimensionalCapacity};
+use crate::extensions::{create_typed_actor_groups, MultiDimensionalCapacity, SpeedFactorTransportCost};
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{add_skills, ApiProblem, ProblemProperties};
 use crate::format::problem::Matrix;
@@ -50,25 +50,43 @@ pub fn create_transport_costs(
         return Err("Amount of fleet profiles does not match matrix profiles".to_string());
     }
 
-    create_matrix_transport_cost(matrix_data)
+    let transport = create_matrix_transport_cost(matrix_data)?;
+
+    let virtual_profiles = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.speed_factor.map(|speed_factor| (vehicle, speed_factor)))
+        .map(|(vehicle, speed_factor)| {
+            fleet_profiles
+                .get(&vehicle.profile)
+                .map(|profile| (*profile, speed_factor))
+                .ok_or_else(|| format!("Unknown vehicle profile: '{}'", vehicle.profile))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(if virtual_profiles.is_empty() {
+        transport
+    } else {
+        Arc::new(SpeedFactorTransportCost::new(transport, fleet_profiles.len(), virtual_profiles))
+    })
 }
 
 pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
-    let profiles = get_profile_map(api_problem);
+    let profiles = get_vehicle_profiles(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
-    api_problem.fleet.vehicles.iter().for_each(|vehicle| {
+    api_problem.fleet.vehicles.iter().zip(profiles.into_iter()).for_each(|(vehicle, profile)| {
         let costs = Costs {
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
-            per_service_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
+            per_service_time: vehicle.costs.service.unwrap_or(vehicle.costs.time),
         };
 
-        let profile = *profiles.get(&vehicle.profile).unwrap() as Profile;
         let areas = vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map(|areas| {
-            areas.iter().map(|area| area.iter().map(|l| (l.lat, l.lng)).collect::<Vec<_>>()).collect::<Vec<_>>()
+            areas.iter().map(|area| area.iter().map(|l| l.to_lat_lng()).collect::<Vec<_>>()).collect::<Vec<_>>()
         });
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
@@ -100,6 +118,14 @@ pub fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_ind
                     dimens.set_value("areas", areas);
                 }
 
+                if let Some(parking) = vehicle.parking {
+                    dimens.set_value("parking", parking);
+                }
+
+                if let Some(service_factor) = vehicle.service_factor {
+                    dimens.set_value("service_factor", service_factor);
+                }
+
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimensionalCapacity::new(vehicle.capacity.clone()));
                 } else {
@@ -150,11 +176,32 @@ pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
-fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
+fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, Profile> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
-            acc.insert(profile.name.clone(), acc.len() as i32);
+            acc.insert(profile.name.clone(), acc.len() as Profile);
         }
         acc
     })
 }
+
+/// Returns routing profile of each vehicle type. A vehicle type with speed factor gets its own
+/// virtual profile, see `SpeedFactorTransportCost`.
+fn get_vehicle_profiles(api_problem: &ApiProblem) -> Vec<Profile> {
+    let profiles = get_profile_map(api_problem);
+    let mut virtual_profile = profiles.len() as Profile;
+
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            if vehicle.speed_factor.is_some() {
+                virtual_profile += 1;
+                virtual_profile - 1
+            } else {
+                *profiles.get(&vehicle.profile).unwrap()
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,270 @@
+//! Contains readers of routing matrix in compact binary and csv formats.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/matrix_reader_test.rs"]
+mod matrix_reader_test;
+
+use super::{deserialize_matrix, Matrix};
+use crate::format::FormatError;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+/// A magic number which starts routing matrix in binary format.
+const BINARY_MAGIC: &[u8; 4] = b"VRPM";
+
+/// A supported version of routing matrix binary format.
+const BINARY_VERSION: u32 = 1;
+
+/// A flag which signalizes that binary matrix contains error codes.
+const BINARY_HAS_ERROR_CODES: u32 = 1;
+
+/// A max length of string in binary format.
+const MAX_STRING_LENGTH: usize = 1024;
+
+/// An amount of values read from binary format at once.
+const CHUNK_SIZE: usize = 4096;
+
+/// Specifies routing matrix format.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatrixFormat {
+    /// A json format, see [`Matrix`].
+    Json,
+    /// A compact binary format: little-endian header followed by `u32` arrays.
+    Binary,
+    /// A csv format where each line specifies travel time and distance between two locations.
+    Csv,
+}
+
+impl MatrixFormat {
+    /// Returns matrix format based on file extension: `bin` for binary, `csv` for csv and json otherwise.
+    pub fn from_path(path: &str) -> Self {
+        let extension = Path::new(path).extension().and_then(|extension| extension.to_str());
+        match extension.map(|extension| extension.to_lowercase()).as_deref() {
+            Some("bin") => MatrixFormat::Binary,
+            Some("csv") => MatrixFormat::Csv,
+            _ => MatrixFormat::Json,
+        }
+    }
+}
+
+impl FromStr for MatrixFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "json" => Ok(MatrixFormat::Json),
+            "binary" => Ok(MatrixFormat::Binary),
+            "csv" => Ok(MatrixFormat::Csv),
+            _ => Err(format!("unknown matrix format: '{}'", value)),
+        }
+    }
+}
+
+/// Deserializes routing matrix in given format from [`BufReader`].
+pub fn deserialize_matrix_with_format<R: Read>(
+    reader: BufReader<R>,
+    format: MatrixFormat,
+) -> Result<Matrix, Vec<FormatError>> {
+    let (result, input) = match format {
+        MatrixFormat::Json => return deserialize_matrix(reader),
+        MatrixFormat::Binary => (read_binary_matrix(reader), "binary"),
+        MatrixFormat::Csv => (read_csv_matrix(reader), "csv"),
+    };
+
+    result.map_err(|err| {
+        vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check input {}: '{}'", input, err),
+        )]
+    })
+}
+
+/// Reads routing matrix in binary format. All numbers are little-endian `u32` values:
+/// * magic bytes `VRPM` and format version
+/// * profile name length and its utf-8 bytes
+/// * timestamp length and its utf-8 bytes, zero length means no timestamp
+/// * amount of locations `n` and flags, first bit signalizes about error codes presence
+/// * `n * n` travel times, `n * n` distances and, optionally, `n * n` error codes
+fn read_binary_matrix<R: Read>(mut reader: BufReader<R>) -> Result<Matrix, String> {
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic).map_err(|_| "cannot read header".to_string())?;
+    if &magic != BINARY_MAGIC {
+        return Err("unexpected magic bytes".to_string());
+    }
+
+    let version = read_u32(&mut reader)?;
+    if version != BINARY_VERSION {
+        return Err(format!("unsupported version: {}", version));
+    }
+
+    let profile = read_string(&mut reader)?;
+    let timestamp = Some(read_string(&mut reader)?).filter(|timestamp| !timestamp.is_empty());
+
+    let size = read_u32(&mut reader)? as usize;
+    let flags = read_u32(&mut reader)?;
+    let length = size.checked_mul(size).ok_or_else(|| format!("too big amount of locations: {}", size))?;
+
+    let travel_times = read_u32_array(&mut reader, length)?;
+    let distances = read_u32_array(&mut reader, length)?;
+    let error_codes =
+        if flags & BINARY_HAS_ERROR_CODES != 0 { Some(read_u32_array(&mut reader, length)?) } else { None };
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes })
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, String> {
+    let mut buffer = [0; 4];
+    reader.read_exact(&mut buffer).map_err(|err| format!("cannot read value: {}", err))?;
+
+    Ok(u32::from_le_bytes(buffer))
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, String> {
+    let length = read_u32(reader)? as usize;
+    if length > MAX_STRING_LENGTH {
+        return Err(format!("too long string: {}", length));
+    }
+
+    let mut buffer = vec![0; length];
+    reader.read_exact(&mut buffer).map_err(|err| format!("cannot read string: {}", err))?;
+
+    String::from_utf8(buffer).map_err(|err| format!("cannot read string: {}", err))
+}
+
+/// Reads array in chunks, so memory is allocated only for data which is actually present in the stream.
+fn read_u32_array<R: Read>(reader: &mut R, length: usize) -> Result<Vec<i64>, String> {
+    let mut values = Vec::with_capacity(length.min(CHUNK_SIZE));
+    let mut buffer = vec![0; CHUNK_SIZE * 4];
+
+    while values.len() < length {
+        let chunk = &mut buffer[..(length - values.len()).min(CHUNK_SIZE) * 4];
+        reader.read_exact(chunk).map_err(|err| format!("cannot read array: {}", err))?;
+
+        values.extend(
+            chunk.chunks_exact(4).map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64),
+        );
+    }
+
+    Ok(values)
+}
+
+/// Reads routing matrix in csv format. Each line has `profile,from,to,duration,distance` values
+/// with optional `error_code` at the end, where `from` and `to` are location indices. Lines should
+/// be sorted by `from` and then by `to` index. The first line can be a header. Travel times and
+/// distances on diagonal are zero if not specified.
+fn read_csv_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, String> {
+    let mut profile: Option<String> = None;
+    let mut entries = CsvEntries::default();
+
+    for (line_idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|err| format!("cannot read line {}: {}", line_idx + 1, err))?;
+        let line = line.trim();
+
+        if line.is_empty() || (line_idx == 0 && line.starts_with("profile")) {
+            continue;
+        }
+
+        let fields = line.split(',').map(|field| field.trim()).collect::<Vec<_>>();
+        if fields.len() != 5 && fields.len() != 6 {
+            return Err(format!("unexpected amount of fields at line {}", line_idx + 1));
+        }
+
+        match profile.as_ref() {
+            Some(profile) if profile != fields[0] => {
+                return Err(format!("different profile at line {}: '{}'", line_idx + 1, fields[0]));
+            }
+            None => profile = Some(fields[0].to_string()),
+            _ => {}
+        }
+
+        let parse_field = |idx: usize| {
+            fields[idx].parse::<i64>().map_err(|err| format!("cannot parse value at line {}: {}", line_idx + 1, err))
+        };
+        let parse_index = |idx: usize| {
+            fields[idx].parse::<usize>().map_err(|err| format!("cannot parse index at line {}: {}", line_idx + 1, err))
+        };
+
+        let error_code = if fields.len() == 6 { Some(parse_field(5)?) } else { None };
+
+        entries
+            .add((parse_index(1)?, parse_index(2)?), (parse_field(3)?, parse_field(4)?, error_code))
+            .map_err(|err| format!("{} at line {}", err, line_idx + 1))?;
+    }
+
+    let profile = profile.ok_or_else(|| "no matrix entries".to_string())?;
+    let (travel_times, distances, error_codes) = entries.finish()?;
+
+    Ok(Matrix { profile, timestamp: None, travel_times, distances, error_codes })
+}
+
+/// Keeps matrix entries read from csv in row-major order.
+#[derive(Default)]
+struct CsvEntries {
+    size: Option<usize>,
+    travel_times: Vec<i64>,
+    distances: Vec<i64>,
+    error_codes: Option<Vec<i64>>,
+}
+
+impl CsvEntries {
+    fn add(&mut self, (from, to): (usize, usize), entry: (i64, i64, Option<i64>)) -> Result<(), String> {
+        // NOTE matrix size is known once the first row is read
+        if self.size.is_none() && from == 1 && !self.travel_times.is_empty() {
+            self.size = Some(self.travel_times.len());
+        }
+
+        let (row, col) = self.next_position();
+        if (row, col) != (from, to) && row == col {
+            self.push((0, 0, None));
+        }
+
+        if self.next_position() != (from, to) || self.size.map_or(false, |size| to >= size) {
+            return Err(format!("unexpected entry from {} to {}, entries should be sorted by indices", from, to));
+        }
+
+        self.push(entry);
+
+        Ok(())
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn finish(mut self) -> Result<(Vec<i64>, Vec<i64>, Option<Vec<i64>>), String> {
+        let size = self.size.unwrap_or_else(|| self.travel_times.len().max(1));
+        self.size = Some(size);
+
+        let (row, col) = self.next_position();
+        self.fill_diagonal(row, col);
+
+        if self.travel_times.len() != size * size {
+            let (row, col) = self.next_position();
+            return Err(format!("missing entry from {} to {}", row, col));
+        }
+
+        Ok((self.travel_times, self.distances, self.error_codes))
+    }
+
+    fn next_position(&self) -> (usize, usize) {
+        let position = self.travel_times.len();
+        self.size.map_or((0, position), |size| (position / size, position % size))
+    }
+
+    fn fill_diagonal(&mut self, row: usize, col: usize) {
+        if row == col && self.next_position() == (row, col) {
+            self.push((0, 0, None));
+        }
+    }
+
+    fn push(&mut self, (duration, distance, error_code): (i64, i64, Option<i64>)) {
+        if error_code.is_some() && self.error_codes.is_none() {
+            self.error_codes = Some(vec![0; self.travel_times.len()]);
+        }
+
+        self.travel_times.push(duration);
+        self.distances.push(distance);
+        if let Some(error_codes) = self.error_codes.as_mut() {
+            error_codes.push(error_code.unwrap_or(0));
+        }
+    }
+}
@@ -1,8 +1,12 @@
-//! Specifies logic to read problem and routing matrix from json input.
+//! Specifies logic to read problem from json input and routing matrix from json, binary or csv input.
 //!
 
 mod model;
 pub use self::model::*;
 
+mod matrix_reader;
+pub use self::matrix_reader::{deserialize_matrix_with_format, MatrixFormat};
+
 mod reader;
+pub(crate) use self::reader::create_approx_matrices;
 pub use self::reader::PragmaticProblem;
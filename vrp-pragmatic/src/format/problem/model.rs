@@ -13,7 +13,7 @@ use std::io::{BufWriter, Write};
 // region Plan
 
 /// Relation type.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum RelationType {
     /// Relation type which  locks jobs to specific vehicle in any order.
@@ -123,6 +123,12 @@ pub struct VehicleCosts {
     pub distance: f64,
     /// Cost per time unit.
     pub time: f64,
+    /// Cost per waiting time unit. If not specified, cost per time unit is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
+    /// Cost per service time unit. If not specified, cost per time unit is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<f64>,
 }
 
 /// Specifies vehicle place.
@@ -245,6 +251,18 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle parking time which is spent once per stop before jobs at the stop are served.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<f64>,
+
+    /// Vehicle service time factor: service durations of jobs served by the vehicle are multiplied by it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_factor: Option<f64>,
+
+    /// Vehicle speed factor: travel durations of vehicle's routing profile are divided by it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_factor: Option<f64>,
 }
 
 /// Specifies routing profile.
@@ -63,6 +63,34 @@ pub fn create_objective(
     })
 }
 
+/// Returns names of primary and secondary objectives in the same order as they are used by objective cost.
+pub fn get_objective_names(api_problem: &ApiProblem) -> (Vec<String>, Vec<String>) {
+    let get_names = |objectives: &Vec<_>| {
+        objectives
+            .iter()
+            .map(|objective| {
+                match objective {
+                    MinimizeCost => "minimize-cost",
+                    MinimizeTours => "minimize-tours",
+                    MaximizeTours => "maximize-tours",
+                    MinimizeUnassignedJobs => "minimize-unassigned",
+                    BalanceMaxLoad { .. } => "balance-max-load",
+                    BalanceActivities { .. } => "balance-activities",
+                    BalanceDistance { .. } => "balance-distance",
+                    BalanceDuration { .. } => "balance-duration",
+                }
+                .to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    if let Some(objectives) = &api_problem.objectives {
+        (get_names(&objectives.primary), get_names(&objectives.secondary.clone().unwrap_or_else(|| vec![])))
+    } else {
+        (get_names(&vec![MinimizeUnassignedJobs, MinimizeTours]), get_names(&vec![MinimizeCost]))
+    }
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
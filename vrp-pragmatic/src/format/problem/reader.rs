@@ -13,7 +13,7 @@ mod objective_reader;
 
 use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
-use self::objective_reader::create_objective;
+use self::objective_reader::{create_objective, get_objective_names};
 use crate::constraints::*;
 use crate::extensions::{MultiDimensionalCapacity, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
@@ -21,7 +21,7 @@ use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
-use crate::{get_unique_locations, parse_time};
+use crate::{get_problem_time_offset, get_unique_locations, parse_time};
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::iter::FromIterator;
@@ -104,13 +104,29 @@ pub struct ProblemProperties {
 }
 
 fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
+    // NOTE routing matrices cannot be approximated when locations are represented by indices
+    if CoordIndex::new(&problem).has_indices() {
+        ValidationContext::new(&problem, None).validate()?;
+    }
+
+    let matrices = create_approx_matrices(&problem);
+
+    map_to_problem(problem, matrices)
+}
+
+/// A speed, in meters per second, used to approximate routing matrices.
+const DEFAULT_SPEED: f64 = 10.;
+
+/// Creates approximated routing matrices for each profile using haversine distance and
+/// constant speed. Vehicle speed factors are applied on top of them by transport costs.
+pub(crate) fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
     let locations = get_unique_locations(&problem);
-    let (durations, distances) = get_approx_transportation(&locations, 10.);
+    let (durations, distances) = get_approx_transportation(&locations, DEFAULT_SPEED);
 
     let durations = durations.into_iter().map(|d| d.round() as i64).collect::<Vec<_>>();
     let distances = distances.into_iter().map(|d| d.round() as i64).collect::<Vec<_>>();
 
-    let matrices = problem
+    problem
         .fleet
         .profiles
         .iter()
@@ -121,9 +137,7 @@ fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<Format
             distances: distances.clone(),
             error_codes: None,
         })
-        .collect();
-
-    map_to_problem(problem, matrices)
+        .collect()
 }
 
 fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
@@ -147,7 +161,7 @@ fn map_to_problem(api_problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Prob
         read_jobs_with_extra_locks(&api_problem, &problem_props, &coord_index, &fleet, &transport, &mut job_index);
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect();
     let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
-    let extras = Arc::new(create_extras(&problem_props, coord_index.clone()));
+    let extras = Arc::new(create_extras(&api_problem, &problem_props, coord_index.clone()));
     let mut constraint = create_constraint_pipeline(
         coord_index,
         &fleet,
@@ -249,19 +263,21 @@ fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIn
         Arc::new(move |location| {
             coord_index
                 .get_by_idx(&location)
-                .map_or_else(|| panic!("Cannot find location!"), |location| (location.lat, location.lng))
+                .map_or_else(|| panic!("Cannot find location!"), |location| location.to_lat_lng())
         }),
         AREA_CONSTRAINT_CODE,
     )));
 }
 
-fn create_extras(props: &ProblemProperties, coord_index: Arc<CoordIndex>) -> Extras {
+fn create_extras(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: Arc<CoordIndex>) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
         "capacity_type".to_string(),
         Arc::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
     );
     extras.insert("coord_index".to_owned(), coord_index);
+    extras.insert("objective_names".to_owned(), Arc::new(get_objective_names(api_problem)));
+    extras.insert("time_offset".to_owned(), Arc::new(get_problem_time_offset(api_problem)));
 
     extras
 }
@@ -0,0 +1,99 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/csv_serializer_test.rs"]
+mod csv_serializer_test;
+
+use super::Solution;
+use crate::format::solution::Stop;
+use crate::format::Location;
+use std::io::{BufWriter, Error, Write};
+
+const CSV_HEADER: &[&str] = &[
+    "vehicle_id",
+    "type_id",
+    "shift_index",
+    "stop_index",
+    "activity_index",
+    "job_id",
+    "activity_type",
+    "job_tag",
+    "lat",
+    "lng",
+    "location_index",
+    "arrival",
+    "departure",
+    "load",
+    "distance",
+];
+
+fn escape_field(field: &str) -> String {
+    if field.contains(|ch| ch == ',' || ch == '"' || ch == '\n' || ch == '\r') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_record<W: Write>(writer: &mut BufWriter<W>, fields: &[String]) -> Result<(), Error> {
+    let record = fields.iter().map(|field| escape_field(field.as_str())).collect::<Vec<_>>().join(",");
+    writeln!(writer, "{}", record)
+}
+
+fn get_stop_records(
+    vehicle_id: &str,
+    type_id: &str,
+    shift_index: usize,
+    stop_index: usize,
+    stop: &Stop,
+) -> Vec<Vec<String>> {
+    stop.activities
+        .iter()
+        .enumerate()
+        .map(|(activity_index, activity)| {
+            let (lat, lng, location_index) = match activity.location.as_ref().unwrap_or(&stop.location) {
+                Location::Coordinate { lat, lng } => (lat.to_string(), lng.to_string(), String::new()),
+                Location::Reference { index } => (String::new(), String::new(), index.to_string()),
+            };
+            let (arrival, departure) = activity.time.as_ref().map_or_else(
+                || (stop.time.arrival.clone(), stop.time.departure.clone()),
+                |time| (time.start.clone(), time.end.clone()),
+            );
+
+            vec![
+                vehicle_id.to_string(),
+                type_id.to_string(),
+                shift_index.to_string(),
+                stop_index.to_string(),
+                activity_index.to_string(),
+                activity.job_id.clone(),
+                activity.activity_type.clone(),
+                activity.job_tag.clone().unwrap_or_default(),
+                lat,
+                lng,
+                location_index,
+                arrival,
+                departure,
+                stop.load.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(" "),
+                stop.distance.to_string(),
+            ]
+        })
+        .collect()
+}
+
+/// Serializes solution into csv format with one record per activity.
+pub fn serialize_solution_as_csv<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let mut writer = writer;
+
+    write_record(&mut writer, CSV_HEADER.iter().map(|field| field.to_string()).collect::<Vec<_>>().as_slice())?;
+
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            tour.stops.iter().enumerate().flat_map(move |(stop_index, stop)| {
+                get_stop_records(tour.vehicle_id.as_str(), tour.type_id.as_str(), tour.shift_index, stop_index, stop)
+            })
+        })
+        .try_for_each(|record| write_record(&mut writer, record.as_slice()))?;
+
+    writer.flush()
+}
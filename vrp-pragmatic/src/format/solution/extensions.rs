@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{CostStatistic, Statistic, Timing};
 use std::ops::Add;
 
 impl Default for Statistic {
@@ -7,7 +7,10 @@ impl Default for Statistic {
             cost: 0.0,
             distance: 0,
             duration: 0,
-            times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+            times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+            gap: None,
+            costs: None,
+            objectives: None,
         }
     }
 }
@@ -25,7 +28,28 @@ impl Add for Statistic {
                 serving: self.times.serving + rhs.times.serving,
                 waiting: self.times.waiting + rhs.times.waiting,
                 break_time: self.times.break_time + rhs.times.break_time,
+                parking: self.times.parking + rhs.times.parking,
             },
+            gap: None,
+            costs: match (self.costs, rhs.costs) {
+                (Some(lhs), Some(rhs)) => Some(lhs + rhs),
+                (lhs, rhs) => lhs.or(rhs),
+            },
+            objectives: None,
+        }
+    }
+}
+
+impl Add for CostStatistic {
+    type Output = CostStatistic;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        CostStatistic {
+            fixed: self.fixed + rhs.fixed,
+            distance: self.distance + rhs.distance,
+            driving: self.driving + rhs.driving,
+            waiting: self.waiting + rhs.waiting,
+            serving: self.serving + rhs.serving,
         }
     }
 }
@@ -1,5 +1,10 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/geo_serializer_test.rs"]
+mod geo_serializer_test;
+
 use super::Solution;
-use crate::format::solution::{Stop, Tour};
+use crate::format::solution::{Stop, Tour, UnassignedJob};
+use crate::format::Location;
 use serde::Serialize;
 use serde_json::Error;
 use std::collections::HashMap;
@@ -25,11 +30,21 @@ struct FeatureCollection {
     pub features: Vec<Feature>,
 }
 
+/// Specifies options of geo json serialization.
+#[derive(Clone, Debug, Default)]
+pub struct GeoJsonOptions {
+    /// Locations of unassigned jobs by job id. Jobs without locations are not serialized.
+    pub unassigned_locations: HashMap<String, Vec<Location>>,
+    /// Specifies whether a json array with feature collection per tour should be written instead of
+    /// single feature collection. Unassigned jobs, if any, are written as the last feature collection.
+    pub per_tour: bool,
+}
+
 fn slice_to_map(vec: &[(&str, &str)]) -> HashMap<String, String> {
     vec.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
 }
 
-fn get_marker_symbol(stop: &Stop) -> String {
+fn get_stop_type(stop: &Stop) -> &'static str {
     let contains_activity_type =
         |activity_type: &str| stop.activities.iter().any(|activity| activity.activity_type == activity_type);
     match (
@@ -38,62 +53,176 @@ fn get_marker_symbol(stop: &Stop) -> String {
         contains_activity_type("break"),
         contains_activity_type("reload"),
     ) {
-        (true, ..) | (_, true, ..) | (.., true) => "warehouse",
-        (.., true, _) => "beer",
+        (true, ..) | (_, true, ..) => "depot",
+        (.., true) => "reload",
+        (.., true, _) => "break",
+        _ => "job",
+    }
+}
+
+fn get_marker_symbol(stop_type: &str) -> &'static str {
+    match stop_type {
+        "depot" => "warehouse",
+        "reload" => "industry",
+        "break" => "beer",
         _ => "marker",
     }
-    .to_string()
 }
 
-fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Feature {
-    Feature {
-        properties: slice_to_map(&[
-            ("marker-color", color),
-            ("marker-size", "medium"),
-            ("marker-symbol", get_marker_symbol(&stop).as_str()),
-            ("tour_idx", tour_idx.to_string().as_str()),
-            ("stop_idx", stop_idx.to_string().as_str()),
-            ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
-        ]),
-        geometry: Geometry::Point { coordinates: (stop.location.lng, stop.location.lat) },
+/// Returns geo json coordinates of the location. Locations represented by index have no coordinates
+/// and are skipped.
+fn get_coordinates(location: &Location) -> Option<(f64, f64)> {
+    match location {
+        Location::Coordinate { lat, lng } => Some((*lng, *lat)),
+        Location::Reference { .. } => None,
     }
 }
 
-fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Feature {
-    Feature {
+fn get_stop_point(tour_idx: usize, stop_idx: usize, stop: &Stop, color: &str) -> Option<Feature> {
+    let coordinates = get_coordinates(&stop.location)?;
+    let stop_type = get_stop_type(stop);
+    let mut properties = slice_to_map(&[
+        ("marker-color", color),
+        ("marker-size", "medium"),
+        ("marker-symbol", get_marker_symbol(stop_type)),
+        ("stop_type", stop_type),
+        ("tour_idx", tour_idx.to_string().as_str()),
+        ("stop_idx", stop_idx.to_string().as_str()),
+        ("jobs_ids", stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(",").as_str()),
+        ("arrival", stop.time.arrival.as_str()),
+        ("departure", stop.time.departure.as_str()),
+        ("load", get_load(stop).as_str()),
+    ]);
+
+    if let (Some(latest_arrival), Some(slack)) = (stop.latest_arrival.as_ref(), stop.slack) {
+        properties.insert("latest_arrival".to_string(), latest_arrival.clone());
+        properties.insert("slack".to_string(), slack.to_string());
+    }
+
+    Some(Feature { properties, geometry: Geometry::Point { coordinates } })
+}
+
+fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Option<Feature> {
+    let coordinates = tour.stops.iter().filter_map(|stop| get_coordinates(&stop.location)).collect::<Vec<_>>();
+    if coordinates.is_empty() {
+        return None;
+    }
+
+    Some(Feature {
         properties: slice_to_map(&[
             ("vehicle_id", tour.vehicle_id.as_str()),
+            ("type_id", tour.type_id.as_str()),
             ("tour_idx", tour_idx.to_string().as_str()),
             ("shift_idx", tour.shift_index.to_string().as_str()),
+            ("stops", tour.stops.len().to_string().as_str()),
             ("activities", tour.stops.iter().map(|stop| stop.activities.len()).sum::<usize>().to_string().as_str()),
             ("distance", (tour.stops.last().unwrap().distance).to_string().as_str()),
+            ("duration", tour.statistic.duration.to_string().as_str()),
+            ("cost", format!("{:.2}", tour.statistic.cost).as_str()),
+            ("load_profile", tour.stops.iter().map(get_load).collect::<Vec<_>>().join(",").as_str()),
             ("stroke-width", "4"),
             ("stroke", color),
         ]),
-        geometry: Geometry::LineString {
-            coordinates: tour.stops.iter().map(|stop| (stop.location.lng, stop.location.lat)).collect(),
-        },
-    }
+        geometry: Geometry::LineString { coordinates },
+    })
 }
 
-/// Serializes solution into geo json format.
-pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
-    let stop_markers = solution.tours.iter().enumerate().flat_map(|(tour_idx, tour)| {
-        tour.stops.iter().enumerate().map(move |(stop_idx, stop)| {
-            get_stop_point(tour_idx, stop_idx, &stop, get_color_inverse(tour_idx).as_str())
+fn get_unassigned_points(unassigned: &UnassignedJob, locations: &[Location]) -> Vec<Feature> {
+    let reasons = unassigned
+        .reasons
+        .iter()
+        .map(|reason| match reason.vehicle_id.as_ref() {
+            Some(vehicle_id) => format!("{}: {} ({})", reason.code, reason.description, vehicle_id),
+            None => format!("{}: {}", reason.code, reason.description),
         })
-    });
+        .collect::<Vec<_>>()
+        .join("; ");
 
-    let stop_lines = solution
-        .tours
+    locations
+        .iter()
+        .filter_map(get_coordinates)
+        .map(|coordinates| Feature {
+            properties: slice_to_map(&[
+                ("marker-color", "#ff0000"),
+                ("marker-size", "medium"),
+                ("marker-symbol", "cross"),
+                ("job_id", unassigned.job_id.as_str()),
+                ("reasons", reasons.as_str()),
+            ]),
+            geometry: Geometry::Point { coordinates },
+        })
+        .collect()
+}
+
+fn get_load(stop: &Stop) -> String {
+    stop.load.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+fn get_tour_features(tour_idx: usize, tour: &Tour) -> Vec<Feature> {
+    tour.stops
         .iter()
         .enumerate()
-        .map(|(tour_idx, tour)| get_tour_line(tour_idx, tour, get_color(tour_idx).as_str()));
+        .filter_map(|(stop_idx, stop)| get_stop_point(tour_idx, stop_idx, &stop, get_color_inverse(tour_idx).as_str()))
+        .chain(get_tour_line(tour_idx, tour, get_color(tour_idx).as_str()).into_iter())
+        .collect()
+}
 
-    serde_json::to_writer_pretty(
-        writer,
-        &FeatureCollection { features: stop_markers.into_iter().chain(stop_lines.into_iter()).collect() },
-    )
+fn get_unassigned_features(solution: &Solution, options: &GeoJsonOptions) -> Vec<Feature> {
+    solution
+        .unassigned
+        .iter()
+        .filter_map(|unassigned| {
+            options.unassigned_locations.get(&unassigned.job_id).map(|locations| (unassigned, locations))
+        })
+        .flat_map(|(unassigned, locations)| get_unassigned_points(unassigned, locations.as_slice()).into_iter())
+        .collect()
+}
+
+/// Serializes solution into geo json format.
+pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    serialize_solution_as_geojson_with_options(writer, solution, &GeoJsonOptions::default())
+}
+
+/// Serializes solution into geo json format using given options.
+pub fn serialize_solution_as_geojson_with_options<W: Write>(
+    writer: BufWriter<W>,
+    solution: &Solution,
+    options: &GeoJsonOptions,
+) -> Result<(), Error> {
+    let unassigned = get_unassigned_features(solution, options);
+
+    if options.per_tour {
+        let collections = solution
+            .tours
+            .iter()
+            .enumerate()
+            .map(|(tour_idx, tour)| FeatureCollection { features: get_tour_features(tour_idx, tour) })
+            .chain(Some(FeatureCollection { features: unassigned }).filter(|c| !c.features.is_empty()).into_iter())
+            .collect::<Vec<_>>();
+
+        serde_json::to_writer_pretty(writer, &collections)
+    } else {
+        let (stop_markers, stop_lines): (Vec<_>, Vec<_>) = solution
+            .tours
+            .iter()
+            .enumerate()
+            .flat_map(|(tour_idx, tour)| get_tour_features(tour_idx, tour).into_iter())
+            .partition(|feature| match feature.geometry {
+                Geometry::Point { .. } => true,
+                Geometry::LineString { .. } => false,
+            });
+
+        serde_json::to_writer_pretty(
+            writer,
+            &FeatureCollection {
+                features: stop_markers
+                    .into_iter()
+                    .chain(stop_lines.into_iter())
+                    .chain(unassigned.into_iter())
+                    .collect(),
+            },
+        )
+    }
 }
 
 fn get_color(idx: usize) -> String {
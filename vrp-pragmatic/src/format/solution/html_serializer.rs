@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/html_serializer_test.rs"]
+mod html_serializer_test;
+
+use super::{serialize_solution_as_geojson_with_options, GeoJsonOptions, Solution};
+use crate::format::solution::{Statistic, Stop, Tour, UnassignedJob};
+use crate::parse_time;
+use std::io::{BufWriter, Error, Write};
+
+// NOTE leaflet assets are shared with docs
+const LEAFLET_CSS: &str = include_str!("../../../../docs/resources/leaflet.css");
+const LEAFLET_JS: &str = include_str!("../../../../docs/resources/leaflet.js");
+
+const STYLE: &str = r#"
+body { font-family: sans-serif; margin: 16px; }
+table { border-collapse: collapse; margin-bottom: 16px; }
+th, td { border: 1px solid #cccccc; padding: 4px 8px; text-align: left; }
+#map { height: 480px; margin-bottom: 16px; }
+.timeline { position: relative; height: 24px; background: #f4f4f4; margin: 4px 0 12px 0; }
+.timeline div { position: absolute; top: 0; height: 24px; min-width: 2px; opacity: 0.85; }
+.job { background: #3388ff; }
+.break { background: #ff9933; }
+.reload { background: #33aa55; }
+.terminal { background: #555555; }
+"#;
+
+const MAP_SCRIPT: &str = r#"
+const map = L.map('map');
+L.tileLayer('https://{s}.tile.openstreetmap.org/{z}/{x}/{y}.png', {
+    maxZoom: 19,
+    attribution: '&copy; <a href="https://openstreetmap.org/copyright">OpenStreetMap contributors</a>'
+}).addTo(map);
+const geojsonLayer = L.geoJSON(JSON.parse(document.getElementById('geojson').textContent), {
+    weight: 2,
+    style: feature => ({ color: feature.properties.stroke || '#000000' }),
+    pointToLayer: (feature, latlng) => L.circleMarker(latlng, {
+        radius: (feature.properties['marker-symbol'] === 'warehouse' ? 10 : 6),
+        fillColor: feature.properties['marker-color'],
+        fillOpacity: 0.75,
+        weight: 2,
+    }),
+    onEachFeature: (feature, layer) => layer.bindPopup('<pre>' + JSON.stringify(feature.properties, null, ' ') + '</pre>')
+});
+geojsonLayer.addTo(map);
+if (geojsonLayer.getLayers().length > 0) {
+    map.fitBounds(geojsonLayer.getBounds());
+} else {
+    map.setView([0, 0], 2);
+}
+"#;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn get_statistic_rows(statistic: &Statistic) -> String {
+    format!(
+        "<td>{:.2}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>",
+        statistic.cost,
+        statistic.distance,
+        statistic.duration,
+        statistic.times.driving,
+        statistic.times.serving,
+        statistic.times.waiting,
+        statistic.times.break_time
+    )
+}
+
+fn get_statistic_table(solution: &Solution) -> String {
+    let header = "<th>cost</th><th>distance</th><th>duration</th><th>driving</th><th>serving</th><th>waiting</th>\
+                  <th>break</th>";
+
+    let tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td>{}</tr>",
+                escape_html(tour.vehicle_id.as_str()),
+                tour.shift_index,
+                tour.stops.len(),
+                tour.stops.iter().map(|stop| stop.activities.len()).sum::<usize>(),
+                get_statistic_rows(&tour.statistic)
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<h2>Statistic</h2>\n<table><tr>{}</tr><tr>{}</tr></table>\n\
+         <h2>Tours</h2>\n<table><tr><th>vehicle</th><th>shift</th><th>stops</th><th>activities</th>{}</tr>{}</table>\n",
+        header,
+        get_statistic_rows(&solution.statistic),
+        header,
+        tours
+    )
+}
+
+fn get_stop_class(stop: &Stop) -> &'static str {
+    let has_activity_type =
+        |activity_type: &str| stop.activities.iter().any(|activity| activity.activity_type == activity_type);
+
+    if has_activity_type("departure") || has_activity_type("arrival") {
+        "terminal"
+    } else if has_activity_type("break") {
+        "break"
+    } else if has_activity_type("reload") {
+        "reload"
+    } else {
+        "job"
+    }
+}
+
+fn get_tour_timeline(tour: &Tour, (start, end): (f64, f64)) -> String {
+    let span = (end - start).max(1.);
+
+    let stops = tour
+        .stops
+        .iter()
+        .map(|stop| {
+            let arrival = parse_time(&stop.time.arrival);
+            let departure = parse_time(&stop.time.departure);
+            let job_ids = stop.activities.iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>();
+
+            format!(
+                "<div class=\"{}\" style=\"left: {:.3}%; width: {:.3}%\" title=\"{}: {} - {}\"></div>",
+                get_stop_class(stop),
+                (arrival - start) / span * 100.,
+                (departure - arrival) / span * 100.,
+                escape_html(job_ids.join(", ").as_str()),
+                stop.time.arrival,
+                stop.time.departure
+            )
+        })
+        .collect::<String>();
+
+    format!(
+        "<div>{} (shift {})</div>\n<div class=\"timeline\">{}</div>\n",
+        escape_html(tour.vehicle_id.as_str()),
+        tour.shift_index,
+        stops
+    )
+}
+
+fn get_timelines(solution: &Solution) -> String {
+    let times = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| vec![parse_time(&stop.time.arrival), parse_time(&stop.time.departure)].into_iter())
+        .collect::<Vec<_>>();
+
+    let start = times.iter().cloned().fold(std::f64::MAX, f64::min);
+    let end = times.iter().cloned().fold(std::f64::MIN, f64::max);
+
+    let timelines = solution.tours.iter().map(|tour| get_tour_timeline(tour, (start, end))).collect::<String>();
+
+    format!("<h2>Timeline</h2>\n{}", timelines)
+}
+
+fn get_unassigned_table(unassigned: &[UnassignedJob]) -> String {
+    let rows = unassigned
+        .iter()
+        .flat_map(|job| {
+            job.reasons.iter().map(move |reason| {
+                format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape_html(job.job_id.as_str()),
+                    reason.code,
+                    escape_html(reason.description.as_str()),
+                    escape_html(reason.vehicle_id.as_ref().map_or("", |vehicle_id| vehicle_id.as_str()))
+                )
+            })
+        })
+        .collect::<String>();
+
+    format!(
+        "<h2>Unassigned jobs ({})</h2>\n<table><tr><th>job</th><th>code</th><th>reason</th><th>vehicle</th></tr>{}</table>\n",
+        unassigned.len(),
+        rows
+    )
+}
+
+/// Serializes solution into html report with map, statistic, tour timelines and unassigned jobs.
+pub fn serialize_solution_as_html<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    serialize_solution_as_html_with_options(writer, solution, &GeoJsonOptions::default())
+}
+
+/// Serializes solution into html report using given geo json options for the map. Map tiles are
+/// loaded from openstreetmap, so the report needs network access to show them.
+pub fn serialize_solution_as_html_with_options<W: Write>(
+    writer: BufWriter<W>,
+    solution: &Solution,
+    options: &GeoJsonOptions,
+) -> Result<(), Error> {
+    // NOTE map expects single feature collection
+    let options = GeoJsonOptions { per_tour: false, ..options.clone() };
+    let mut geojson = Vec::new();
+    serialize_solution_as_geojson_with_options(BufWriter::new(&mut geojson), solution, &options)?;
+    // NOTE prevent closing script tag inside of embedded json
+    let geojson = String::from_utf8_lossy(geojson.as_slice()).replace("</", "<\\/");
+
+    let mut writer = writer;
+
+    write!(
+        writer,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Solution report</title>\n\
+         <style>{}</style>\n<script>{}</script>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>Solution report</h1>\n<div id=\"map\"></div>\n{}{}{}\
+         <script type=\"application/json\" id=\"geojson\">{}</script>\n<script>{}</script>\n</body>\n</html>\n",
+        LEAFLET_CSS,
+        LEAFLET_JS,
+        STYLE,
+        get_statistic_table(solution),
+        get_timelines(solution),
+        get_unassigned_table(solution.unassigned.as_slice()),
+        geojson,
+        MAP_SCRIPT
+    )?;
+
+    writer.flush()
+}
@@ -1,13 +1,25 @@
-//! Specifies logic to create a "pragmatic" solution and write it into json format.
+//! Specifies logic to create a "pragmatic" solution and write it into json, geojson, csv or html format
+//! or to convert it into relations.
 
 mod model;
 pub use self::model::*;
 
 mod geo_serializer;
-pub use self::geo_serializer::serialize_solution_as_geojson;
+pub use self::geo_serializer::{
+    serialize_solution_as_geojson, serialize_solution_as_geojson_with_options, GeoJsonOptions,
+};
+
+mod csv_serializer;
+pub use self::csv_serializer::serialize_solution_as_csv;
+
+mod html_serializer;
+pub use self::html_serializer::{serialize_solution_as_html, serialize_solution_as_html_with_options};
+
+mod relation_writer;
+pub use self::relation_writer::{create_relations, merge_relations};
 
 mod extensions;
 
 mod writer;
-pub use self::writer::create_solution;
 pub use self::writer::PragmaticSolution;
+pub use self::writer::{create_geojson_options, create_solution};
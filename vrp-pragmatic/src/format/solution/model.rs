@@ -15,6 +15,9 @@ pub struct Timing {
     /// Break time.
     #[serde(rename(serialize = "break", deserialize = "break"))]
     pub break_time: i32,
+    /// Parking time.
+    #[serde(default)]
+    pub parking: i32,
 }
 
 /// Represents statistic.
@@ -28,6 +31,57 @@ pub struct Statistic {
     pub duration: i32,
     /// Timing statistic.
     pub times: Timing,
+    /// Optimality gap. Present only in total statistic when lower bounds are estimated.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub gap: Option<Gap>,
+    /// Cost breakdown by cost type.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub costs: Option<CostStatistic>,
+    /// Values of configured objectives. Present only in total statistic.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub objectives: Option<ObjectiveStatistic>,
+}
+
+/// Represents total cost split by cost type.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug, Default)]
+pub struct CostStatistic {
+    /// Fixed cost of vehicle usage.
+    pub fixed: f64,
+    /// Distance cost.
+    pub distance: f64,
+    /// Driving time cost.
+    pub driving: f64,
+    /// Waiting time cost.
+    pub waiting: f64,
+    /// Service time cost, including break time.
+    pub serving: f64,
+}
+
+/// Represents objective value.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ObjectiveValue {
+    /// Objective name.
+    pub name: String,
+    /// Objective value.
+    pub value: f64,
+}
+
+/// Represents values of primary and secondary objectives.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ObjectiveStatistic {
+    /// Values of primary objectives.
+    pub primary: Vec<ObjectiveValue>,
+    /// Values of secondary objectives.
+    pub secondary: Vec<ObjectiveValue>,
+}
+
+/// Represents difference (in percents) between solution's objective values and their lower bounds.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct Gap {
+    /// A gap of tours amount.
+    pub tours: f64,
+    /// A gap of total cost.
+    pub cost: f64,
 }
 
 /// Represents a schedule.
@@ -71,6 +125,7 @@ pub struct Activity {
 
 /// A stop is a place where vehicle is supposed to be parked.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Stop {
     /// Stop location.
     pub location: Location,
@@ -82,6 +137,12 @@ pub struct Stop {
     pub load: Vec<i32>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
+    /// Latest arrival time at the stop which does not violate time windows of the rest of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_arrival: Option<String>,
+    /// Difference in seconds between latest and planned arrival.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slack: Option<i32>,
 }
 
 /// A tour is list of stops with their activities performed by specific vehicle.
@@ -102,11 +163,15 @@ pub struct Tour {
 
 /// Unassigned job reason.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct UnassignedJobReason {
     /// A reason code.
     pub code: i32,
     /// Description.
     pub description: String,
+    /// Id of vehicle which cannot serve the job due to the reason.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
 }
 
 /// Unassigned job.
@@ -0,0 +1,124 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/relation_writer_test.rs"]
+mod relation_writer_test;
+
+use super::{Solution, Tour};
+use crate::format::problem::{Job, Problem, Relation, RelationType};
+use std::collections::{HashMap, HashSet};
+
+fn create_relation(tour: &Tour, relation_type: RelationType) -> Relation {
+    let mut visited = HashSet::new();
+    let mut has_skipped = false;
+
+    let jobs = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.clone())
+        .filter(|job_id| match job_id.as_str() {
+            "departure" | "arrival" => true,
+            // NOTE reserved break and reload ids are matched to vehicle shift's breaks and reloads by their
+            // position, so they are skipped as it is unknown which ones are served
+            "break" | "reload" => {
+                has_skipped = true;
+                false
+            }
+            // NOTE job with multiple activities (e.g. pickup and delivery) is referenced once
+            _ => visited.insert(job_id.clone()),
+        })
+        .collect();
+
+    Relation {
+        type_field: downgrade_relation_type(relation_type, has_skipped),
+        jobs,
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: Some(tour.shift_index),
+    }
+}
+
+/// Returns `sequence` instead of `strict` relation type when some tour items are skipped: otherwise,
+/// jobs around skipped items become strict neighbours which they are not in the tour.
+fn downgrade_relation_type(relation_type: RelationType, has_skipped: bool) -> RelationType {
+    match relation_type {
+        RelationType::Strict if has_skipped => RelationType::Sequence,
+        _ => relation_type,
+    }
+}
+
+/// Creates relations of given type from solution's tours, one relation per tour. Job ids are listed
+/// in the order of visiting including reserved `departure` and `arrival` ids. Breaks and reloads are skipped
+/// and `strict` relation of such tour is created as `sequence` one. When vehicle ids are specified, only tours
+/// of these vehicles are converted.
+pub fn create_relations(
+    solution: &Solution,
+    relation_type: RelationType,
+    vehicle_ids: Option<&[String]>,
+) -> Vec<Relation> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| vehicle_ids.map_or(true, |vehicle_ids| vehicle_ids.contains(&tour.vehicle_id)))
+        .map(|tour| create_relation(tour, relation_type.clone()))
+        .collect()
+}
+
+fn has_multiple_places_or_times(job: &Job) -> bool {
+    job.pickups
+        .iter()
+        .chain(job.deliveries.iter())
+        .chain(job.replacements.iter())
+        .chain(job.services.iter())
+        .flat_map(|tasks| tasks.iter())
+        .any(|task| {
+            task.places.len() > 1
+                || task.places.iter().any(|place| place.times.as_ref().map_or(false, |tw| tw.len() > 1))
+        })
+}
+
+/// Merges relations into problem's plan. Existing relations of the same vehicle shifts are replaced.
+/// Jobs with multiple places or time windows are removed from `sequence` and `strict` relations as
+/// they are not supported there. A `strict` relation with removed jobs is merged as `sequence` one.
+pub fn merge_relations(problem: Problem, relations: Vec<Relation>) -> Problem {
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+    let relations = relations
+        .into_iter()
+        .map(|relation| match relation.type_field {
+            RelationType::Any => relation,
+            _ => {
+                let total = relation.jobs.len();
+                let jobs = relation
+                    .jobs
+                    .into_iter()
+                    .filter(|job_id| jobs.get(job_id.as_str()).map_or(true, |job| !has_multiple_places_or_times(job)))
+                    .collect::<Vec<_>>();
+
+                Relation {
+                    type_field: downgrade_relation_type(relation.type_field, jobs.len() != total),
+                    jobs,
+                    ..relation
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let shifts = relations
+        .iter()
+        .map(|relation| (relation.vehicle_id.clone(), relation.shift_index.unwrap_or(0)))
+        .collect::<HashSet<_>>();
+
+    let mut problem = problem;
+
+    let merged = problem
+        .plan
+        .relations
+        .take()
+        .unwrap_or_else(Vec::new)
+        .into_iter()
+        .filter(|relation| !shifts.contains(&(relation.vehicle_id.clone(), relation.shift_index.unwrap_or(0))))
+        .chain(relations.into_iter())
+        .collect::<Vec<_>>();
+
+    problem.plan.relations = if merged.is_empty() { None } else { Some(merged) };
+
+    problem
+}
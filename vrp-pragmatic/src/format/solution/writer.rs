@@ -6,17 +6,23 @@ use crate::extensions::MultiDimensionalCapacity;
 use crate::format::coord_index::CoordIndex;
 use crate::format::solution::model::Timing;
 use crate::format::solution::{
-    serialize_solution, serialize_solution_as_geojson, Activity, Extras, Interval, Statistic, Stop, Tour,
-    UnassignedJob, UnassignedJobReason,
+    serialize_solution, serialize_solution_as_csv, serialize_solution_as_geojson_with_options,
+    serialize_solution_as_html_with_options, Activity, CostStatistic, Extras, Gap, GeoJsonOptions, Interval,
+    ObjectiveStatistic, ObjectiveValue, Statistic, Stop, Tour, UnassignedJob, UnassignedJobReason,
 };
 use crate::format::*;
-use crate::format_time;
+use crate::format_time_with_offset;
+use std::collections::HashMap;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension};
+use std::sync::Arc;
+use vrp_core::construction::constraints::{route_intervals, Demand, DemandDimension, LATEST_ARRIVAL_KEY};
+use vrp_core::construction::heuristics::*;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Job, Multi};
+use vrp_core::models::problem::{Actor, Job, Multi};
 use vrp_core::models::solution::{Route, TourActivity};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::solver::OptimalityGap;
+use vrp_core::utils::DefaultRandom;
 
 type ApiSolution = crate::format::solution::model::Solution;
 type ApiSchedule = crate::format::solution::model::Schedule;
@@ -30,6 +36,15 @@ pub trait PragmaticSolution<W: Write> {
 
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution in pragmatic geo json format as a list of feature collections, one per tour.
+    fn write_geo_json_per_tour(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution in csv format as a flat list of activities.
+    fn write_pragmatic_csv(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution as html report with map, tour statistics, timelines and unassigned jobs.
+    fn write_html_report(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> PragmaticSolution<W> for Solution {
@@ -41,7 +56,28 @@ impl<W: Write> PragmaticSolution<W> for Solution {
 
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         let solution = create_solution(problem, &self);
-        serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
+        let options = create_geojson_options(&self, false);
+        serialize_solution_as_geojson_with_options(writer, &solution, &options).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
+    fn write_geo_json_per_tour(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution(problem, &self);
+        let options = create_geojson_options(&self, true);
+        serialize_solution_as_geojson_with_options(writer, &solution, &options).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
+    fn write_pragmatic_csv(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution(problem, &self);
+        serialize_solution_as_csv(writer, &solution).map_err(|err| err.to_string())?;
+        Ok(())
+    }
+
+    fn write_html_report(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution(problem, &self);
+        let options = create_geojson_options(&self, false);
+        serialize_solution_as_html_with_options(writer, &solution, &options).map_err(|err| err.to_string())?;
         Ok(())
     }
 }
@@ -68,25 +104,36 @@ impl Leg {
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution) -> ApiSolution {
-    let coord_index = solution
-        .extras
-        .get("coord_index")
-        .and_then(|s| s.downcast_ref::<CoordIndex>())
-        .unwrap_or_else(|| panic!("Cannot get coord index!"));
+    let coord_index = get_coord_index(solution);
+
+    let tours = solution
+        .routes
+        .iter()
+        .map(|route| create_tour(problem, &create_route_context(problem, route), coord_index))
+        .collect::<Vec<Tour>>();
 
-    let tours = solution.routes.iter().map(|r| create_tour(problem, r, coord_index)).collect::<Vec<Tour>>();
+    let insertion_ctx = create_insertion_context(problem, solution);
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+    let statistic = Statistic {
+        gap: create_gap(solution),
+        costs: Some(statistic.costs.clone().unwrap_or_default()),
+        objectives: create_objective_statistic(&insertion_ctx),
+        ..statistic
+    };
 
-    let unassigned = create_unassigned(solution);
+    let unassigned = create_unassigned(&insertion_ctx, solution);
 
     let extras = create_extras(solution);
 
     ApiSolution { statistic, tours, unassigned, extras }
 }
 
-fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
+fn create_tour(problem: &Problem, route_ctx: &RouteContext, coord_index: &CoordIndex) -> Tour {
+    let route = route_ctx.route.as_ref();
     let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+    let offset = get_time_offset(problem.extras.as_ref());
+    let format_time = |time: f64| format_time_with_offset(time, offset);
 
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
@@ -120,7 +167,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             let start = route.tour.start().unwrap();
             tour.stops.push(Stop {
                 location: coord_index.get_by_idx(&start.place.location).unwrap(),
-                time: format_schedule(&start.schedule),
+                time: format_schedule(&start.schedule, offset),
                 load: start_delivery.as_vec(),
                 distance: 0,
                 activities: vec![Activity {
@@ -130,6 +177,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     time: None,
                     job_tag: None,
                 }],
+                latest_arrival: None,
+                slack: None,
             });
             (start_idx + 1, start)
         } else {
@@ -165,6 +214,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let driving =
                     problem.transport.duration(vehicle.profile, prev_location, act.place.location, prev_departure);
                 let arrival = prev_departure + driving;
+                let parking =
+                    if prev_location != act.place.location { problem.activity.parking(actor, act) } else { 0. };
                 let start = act.schedule.arrival.max(act.place.time.start);
                 let waiting = start - act.schedule.arrival;
                 let serving = problem.activity.duration(route.actor.as_ref(), act, act.schedule.arrival);
@@ -173,18 +224,24 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 // total cost and distance
                 let cost = leg.statistic.cost
                     + problem.activity.cost(actor, act, act.schedule.arrival)
+                    + parking * (actor.driver.costs.per_service_time + vehicle.costs.per_service_time)
                     + problem.transport.cost(actor, prev_location, act.place.location, prev_departure);
-                let distance = leg.statistic.distance
-                    + problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure)
-                        as i32;
+                let leg_distance =
+                    problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure);
+                let distance = leg.statistic.distance + leg_distance as i32;
+                let costs = leg.statistic.costs.clone().unwrap_or_default()
+                    + get_cost_statistic(actor, leg_distance, driving, waiting, serving, parking);
 
                 if prev_location != act.place.location {
+                    let latest_arrival = get_latest_arrival(route_ctx, act);
                     tour.stops.push(Stop {
                         location: coord_index.get_by_idx(&act.place.location).unwrap(),
-                        time: format_as_schedule(&(arrival, departure)),
+                        time: format_as_schedule(&(arrival, departure), offset),
                         load: prev_load.as_vec(),
                         distance,
                         activities: vec![],
+                        latest_arrival: latest_arrival.map(format_time),
+                        slack: latest_arrival.map(|latest_arrival| (latest_arrival - arrival).round() as i32),
                     });
                 }
 
@@ -214,7 +271,11 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i32 }),
                             waiting: leg.statistic.times.waiting + waiting as i32,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i32 } else { 0 }),
+                            parking: leg.statistic.times.parking + parking as i32,
                         },
+                        gap: None,
+                        costs: Some(costs),
+                        objectives: None,
                     },
                     load: Some(load),
                 }
@@ -237,6 +298,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         });
 
     leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.costs.get_or_insert_with(CostStatistic::default).fixed += vehicle.costs.fixed;
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
@@ -245,12 +307,60 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     tour
 }
 
-fn format_schedule(schedule: &Schedule) -> ApiSchedule {
-    ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
+/// Splits activity and transport costs by cost type. Activity costs are calculated using vehicle costs only,
+/// parking is charged using both driver and vehicle service costs as it is done by solver.
+fn get_cost_statistic(
+    actor: &Actor,
+    distance: f64,
+    driving: f64,
+    waiting: f64,
+    serving: f64,
+    parking: f64,
+) -> CostStatistic {
+    let (vehicle, driver) = (&actor.vehicle.costs, &actor.driver.costs);
+
+    CostStatistic {
+        fixed: 0.,
+        distance: distance * (driver.per_distance + vehicle.per_distance),
+        driving: driving * (driver.per_driving_time + vehicle.per_driving_time),
+        waiting: waiting * vehicle.per_waiting_time,
+        serving: serving * vehicle.per_service_time + parking * (driver.per_service_time + vehicle.per_service_time),
+    }
+}
+
+/// Creates route context with actual route state.
+fn create_route_context(problem: &Problem, route: &Route) -> RouteContext {
+    let mut route_ctx = RouteContext::new_with_state(Arc::new(route.deep_copy()), Arc::new(RouteState::default()));
+    problem.constraint.accept_route_state(&mut route_ctx);
+
+    route_ctx
 }
 
-fn format_as_schedule(schedule: &(f64, f64)) -> ApiSchedule {
-    format_schedule(&Schedule::new(schedule.0, schedule.1))
+/// Returns latest arrival time at activity which keeps the rest of the tour feasible.
+fn get_latest_arrival(route_ctx: &RouteContext, activity: &TourActivity) -> Option<f64> {
+    let latest_arrival = if activity.job.is_some() {
+        route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, activity).cloned()
+    } else {
+        Some(activity.place.time.end.min(route_ctx.route.actor.detail.time.end))
+    };
+
+    latest_arrival.filter(|latest_arrival| *latest_arrival < std::f64::MAX)
+}
+
+/// Returns offset from UTC, in seconds, used to format solution times.
+fn get_time_offset(extras: &DomainExtras) -> i32 {
+    extras.get("time_offset").and_then(|offset| offset.downcast_ref::<i32>()).cloned().unwrap_or(0)
+}
+
+fn format_schedule(schedule: &Schedule, offset: i32) -> ApiSchedule {
+    ApiSchedule {
+        arrival: format_time_with_offset(schedule.arrival, offset),
+        departure: format_time_with_offset(schedule.departure, offset),
+    }
+}
+
+fn format_as_schedule(schedule: &(f64, f64), offset: i32) -> ApiSchedule {
+    format_schedule(&Schedule::new(schedule.0, schedule.1), offset)
 }
 
 fn calculate_load(
@@ -265,37 +375,195 @@ fn calculate_load(
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn create_unassigned(solution: &Solution) -> Vec<UnassignedJob> {
+fn create_unassigned(insertion_ctx: &InsertionContext, solution: &Solution) -> Vec<UnassignedJob> {
+    let explanations = explain_unassigned(insertion_ctx, solution);
+
     solution.unassigned.iter().fold(vec![], |mut acc, unassigned| {
-        let reason = match *unassigned.1 {
-            SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
-            TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
-            CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
-            REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
-            DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
-            DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
-            BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
-            LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
-            PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
-            AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
-            _ => (0, "unknown"),
-        };
-        let dimens = match unassigned.0 {
-            Job::Single(job) => &job.dimens,
-            Job::Multi(job) => &job.dimens,
-        };
-        acc.push(UnassignedJob {
-            job_id: dimens
-                .get_value::<String>("vehicle_id")
-                .map(|vehicle_id| format!("{}_break", vehicle_id))
-                .unwrap_or_else(|| dimens.get_id().unwrap().clone()),
-            reasons: vec![UnassignedJobReason { code: reason.0, description: reason.1.to_string() }],
-        });
+        let dimens = get_job_dimens(unassigned.0);
+
+        let reasons = explanations
+            .get(unassigned.0)
+            .filter(|reasons| !reasons.is_empty())
+            .map(|reasons| {
+                reasons
+                    .iter()
+                    .map(|(vehicle_id, code)| {
+                        let (code, description) = get_unassigned_reason(*code);
+                        UnassignedJobReason {
+                            code,
+                            description: description.to_string(),
+                            vehicle_id: Some(vehicle_id.clone()),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_else(|| {
+                let (code, description) = get_unassigned_reason(*unassigned.1);
+                vec![UnassignedJobReason { code, description: description.to_string(), vehicle_id: None }]
+            });
+
+        acc.push(UnassignedJob { job_id: get_unassigned_job_id(dimens), reasons });
 
         acc
     })
 }
 
+fn get_unassigned_job_id(dimens: &Dimensions) -> String {
+    dimens
+        .get_value::<String>("vehicle_id")
+        .map(|vehicle_id| format!("{}_break", vehicle_id))
+        .unwrap_or_else(|| dimens.get_id().unwrap().clone())
+}
+
+fn get_coord_index(solution: &Solution) -> &CoordIndex {
+    solution
+        .extras
+        .get("coord_index")
+        .and_then(|s| s.downcast_ref::<CoordIndex>())
+        .unwrap_or_else(|| panic!("Cannot get coord index!"))
+}
+
+/// Creates geo json options with locations of unassigned jobs.
+pub fn create_geojson_options(solution: &Solution, per_tour: bool) -> GeoJsonOptions {
+    let coord_index = get_coord_index(solution);
+
+    let unassigned_locations = solution
+        .unassigned
+        .keys()
+        .map(|job| {
+            let singles = match job {
+                Job::Single(single) => vec![single.clone()],
+                Job::Multi(multi) => multi.jobs.clone(),
+            };
+
+            let locations = singles
+                .iter()
+                .flat_map(|single| single.places.iter())
+                .filter_map(|place| place.location.as_ref())
+                .filter_map(|location| coord_index.get_by_idx(location))
+                .collect();
+
+            (get_unassigned_job_id(get_job_dimens(job)), locations)
+        })
+        .collect();
+
+    GeoJsonOptions { unassigned_locations, per_tour }
+}
+
+/// Creates insertion context with restored state from given solution.
+fn create_insertion_context(problem: &Problem, solution: &Solution) -> InsertionContext {
+    // NOTE insertion context requires shared ownership, so problem is shallow copied and solution is deep copied
+    let problem = Arc::new(problem.clone());
+    let solution = Arc::new(Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: solution.unassigned.clone(),
+        extras: solution.extras.clone(),
+    });
+
+    let mut ctx = InsertionContext::new_from_solution(problem, (solution, None), Arc::new(DefaultRandom::default()));
+    ctx.restore();
+
+    ctx
+}
+
+/// Returns values of primary and secondary objectives if their names are known.
+fn create_objective_statistic(insertion_ctx: &InsertionContext) -> Option<ObjectiveStatistic> {
+    let problem = insertion_ctx.problem.as_ref();
+    let (primary, secondary) =
+        problem.extras.get("objective_names").and_then(|names| names.downcast_ref::<(Vec<String>, Vec<String>)>())?;
+
+    let mut values = problem.objective.objectives().map(|objective| objective.fitness(insertion_ctx));
+
+    let mut get_values = |names: &Vec<String>| {
+        names
+            .iter()
+            .zip(values.by_ref())
+            .map(|(name, value)| ObjectiveValue { name: name.clone(), value })
+            .collect::<Vec<_>>()
+    };
+
+    let primary = get_values(primary);
+    let secondary = get_values(secondary);
+
+    Some(ObjectiveStatistic { primary, secondary })
+}
+
+/// Evaluates insertion of each unassigned job into route of every actor and returns failed
+/// constraint codes per vehicle id. Breaks are skipped as they belong to specific vehicle.
+fn explain_unassigned(insertion_ctx: &InsertionContext, solution: &Solution) -> HashMap<Job, Vec<(String, i32)>> {
+    let jobs = solution
+        .unassigned
+        .keys()
+        .filter(|job| get_job_dimens(job).get_value::<String>("vehicle_id").is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if jobs.is_empty() {
+        return HashMap::default();
+    }
+
+    let route_ctxs = insertion_ctx
+        .problem
+        .fleet
+        .actors
+        .iter()
+        .map(|actor| {
+            insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .find(|route_ctx| route_ctx.route.actor == *actor)
+                .cloned()
+                .unwrap_or_else(|| RouteContext::new(actor.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    jobs.into_iter()
+        .map(|job| {
+            let reasons = route_ctxs.iter().fold(Vec::<(String, i32)>::new(), |mut acc, route_ctx| {
+                let result =
+                    evaluate_job_insertion_in_route(&job, insertion_ctx, route_ctx, InsertionPosition::Any, None);
+
+                if let InsertionResult::Failure(failure) = result {
+                    let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_id().unwrap().clone();
+                    let reason = (vehicle_id, failure.constraint);
+                    if !acc.contains(&reason) {
+                        acc.push(reason);
+                    }
+                }
+
+                acc
+            });
+
+            (job, reasons)
+        })
+        .collect()
+}
+
+fn get_unassigned_reason(code: i32) -> (i32, &'static str) {
+    match code {
+        SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
+        TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
+        CAPACITY_CONSTRAINT_CODE => (3, "does not fit into any vehicle due to capacity"),
+        REACHABLE_CONSTRAINT_CODE => (100, "location unreachable"),
+        DISTANCE_LIMIT_CONSTRAINT_CODE => (101, "cannot be assigned due to max distance constraint of vehicle"),
+        DURATION_LIMIT_CONSTRAINT_CODE => (102, "cannot be assigned due to shift time constraint of vehicle"),
+        BREAK_CONSTRAINT_CODE => (103, "break is not assignable"),
+        LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
+        PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
+        AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
+        _ => (0, "unknown"),
+    }
+}
+
+fn get_job_dimens(job: &Job) -> &Dimensions {
+    match job {
+        Job::Single(job) => &job.dimens,
+        Job::Multi(job) => &job.dimens,
+    }
+}
+
 fn get_activity_type(activity: &TourActivity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
 }
@@ -330,6 +598,14 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
+fn create_gap(solution: &Solution) -> Option<Gap> {
+    solution
+        .extras
+        .get("optimality_gap")
+        .and_then(|s| s.downcast_ref::<OptimalityGap>())
+        .map(|gap| Gap { tours: gap.tours, cost: gap.cost })
+}
+
 fn create_extras(solution: &Solution) -> Option<Extras> {
     if solution.extras.get("iterations").is_some() {
         unimplemented!()
@@ -22,22 +22,39 @@ pub mod slow;
 mod constraints;
 mod extensions;
 mod utils;
-mod validation;
 
 pub mod checker;
+pub mod diff;
 pub mod format;
+pub mod validation;
 
 use crate::format::problem::Problem;
 use crate::format::{CoordIndex, Location};
-use chrono::{DateTime, ParseError, SecondsFormat, TimeZone, Utc};
+use chrono::{DateTime, FixedOffset, ParseError, SecondsFormat, TimeZone};
 
 /// Get lists of problem.
 pub fn get_unique_locations(problem: &Problem) -> Vec<Location> {
     CoordIndex::new(&problem).unique()
 }
 
+/// Returns offset from UTC, in seconds, used by the first vehicle shift start time. Solution times
+/// are formatted using this offset.
+fn get_problem_time_offset(problem: &Problem) -> i32 {
+    problem
+        .fleet
+        .vehicles
+        .first()
+        .and_then(|vehicle| vehicle.shifts.first())
+        .and_then(|shift| parse_time_offset_safe(&shift.start.time).ok())
+        .unwrap_or(0)
+}
+
 fn format_time(time: f64) -> String {
-    Utc.timestamp(time as i64, 0).to_rfc3339_opts(SecondsFormat::Secs, true)
+    format_time_with_offset(time, 0)
+}
+
+fn format_time_with_offset(time: f64, offset: i32) -> String {
+    FixedOffset::east(offset).timestamp(time as i64, 0).to_rfc3339_opts(SecondsFormat::Secs, true)
 }
 
 fn parse_time(time: &String) -> f64 {
@@ -47,3 +64,7 @@ fn parse_time(time: &String) -> f64 {
 fn parse_time_safe(time: &String) -> Result<f64, ParseError> {
     DateTime::parse_from_rfc3339(time).map(|time| time.timestamp() as f64)
 }
+
+fn parse_time_offset_safe(time: &String) -> Result<i32, ParseError> {
+    DateTime::parse_from_rfc3339(time).map(|time| time.offset().local_minus_utc())
+}
@@ -22,11 +22,13 @@ pub fn get_approx_transportation(locations: &Vec<Location>, speed: f64) -> (Vec<
 
 /// Gets distance between two points using haversine formula.
 fn get_distance(p1: &Location, p2: &Location) -> f64 {
-    let d_lat = degree_rad(p1.lat - p2.lat);
-    let d_lng = degree_rad(p1.lng - p2.lng);
+    let ((lat1, lng1), (lat2, lng2)) = (p1.to_lat_lng(), p2.to_lat_lng());
 
-    let lat1 = degree_rad(p1.lat);
-    let lat2 = degree_rad(p2.lat);
+    let d_lat = degree_rad(lat1 - lat2);
+    let d_lng = degree_rad(lng1 - lng2);
+
+    let lat1 = degree_rad(lat1);
+    let lat2 = degree_rad(lat2);
 
     let a =
         (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
@@ -0,0 +1,255 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/feasibility_test.rs"]
+mod feasibility_test;
+
+use super::*;
+use crate::format::{CoordIndex, Location};
+use crate::parse_time_offset_safe;
+use std::collections::BTreeSet;
+
+/// Checks that every job's demand fits into capacity of at least one vehicle.
+fn check_w1000_job_demand_fits_capacity(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = get_job_ids(ctx, |job| !ctx.vehicles().any(|vehicle| can_fit_demand(ctx, job, vehicle)));
+
+    create_warning(
+        "W1000",
+        "job demand exceeds capacity of every vehicle",
+        "reduce demand or increase vehicle capacity for jobs",
+        ids,
+    )
+}
+
+/// Checks that every job's required skills are present in at least one vehicle.
+fn check_w1001_job_skills_are_available(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = get_job_ids(ctx, |job| !ctx.vehicles().any(|vehicle| has_skills(job, vehicle)));
+
+    create_warning(
+        "W1001",
+        "job requires skills which no vehicle has",
+        "remove skills from jobs or add them to vehicles, jobs",
+        ids,
+    )
+}
+
+/// Checks that every job's time windows intersect with at least one vehicle shift.
+fn check_w1002_job_time_windows_match_shifts(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = get_job_ids(ctx, |job| {
+        !ctx.vehicles().flat_map(|vehicle| vehicle.shifts.iter()).any(|shift| can_serve_in_time(ctx, job, shift))
+    });
+
+    create_warning(
+        "W1002",
+        "job time windows are outside of every vehicle shift",
+        "correct time windows of jobs or vehicle shifts, jobs",
+        ids,
+    )
+}
+
+/// Checks that every job can be reached from start location of at least one vehicle shift.
+fn check_w1003_job_locations_are_reachable(ctx: &ValidationContext, routing: &Routing) -> Result<(), FormatError> {
+    let ids = get_job_ids(ctx, |job| {
+        !ctx.vehicles().any(|vehicle| vehicle.shifts.iter().any(|shift| routing.can_reach(ctx, job, vehicle, shift)))
+    });
+
+    create_warning(
+        "W1003",
+        "job locations are unreachable from every vehicle start location",
+        "correct locations or routing matrix error codes for jobs",
+        ids,
+    )
+}
+
+/// Checks that jobs in relations can be served by relation's vehicle shift.
+fn check_w1004_relation_jobs_can_be_served(ctx: &ValidationContext, routing: &Routing) -> Result<(), FormatError> {
+    let ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .flat_map(|relation| {
+            let vehicle_shift =
+                ctx.vehicles().find(|vehicle| vehicle.vehicle_ids.contains(&relation.vehicle_id)).and_then(|vehicle| {
+                    vehicle.shifts.get(relation.shift_index.unwrap_or(0)).map(|shift| (vehicle, shift))
+                });
+
+            relation
+                .jobs
+                .iter()
+                .filter_map(|job_id| ctx.job_index.get(job_id))
+                .filter(move |job| {
+                    vehicle_shift.map_or(false, |(vehicle, shift)| {
+                        !(can_fit_demand(ctx, job, vehicle)
+                            && has_skills(job, vehicle)
+                            && can_serve_in_time(ctx, job, shift)
+                            && routing.can_reach(ctx, job, vehicle, shift))
+                    })
+                })
+                .map(|job| job.id.clone())
+        })
+        .collect::<Vec<_>>();
+
+    create_warning(
+        "W1004",
+        "job in relation cannot be served by relation's vehicle shift",
+        "correct relations or jobs",
+        ids,
+    )
+}
+
+/// Checks that all problem times use the same offset from UTC.
+fn check_w1005_times_have_same_offset(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_times = ctx
+        .jobs()
+        .flat_map(|job| ctx.tasks(job).into_iter())
+        .flat_map(|task| task.places.iter())
+        .flat_map(|place| place.times.iter().flat_map(|times| times.iter()));
+
+    let shift_times = ctx.vehicles().flat_map(|vehicle| vehicle.shifts.iter()).flat_map(|shift| {
+        let place_times = std::iter::once(&shift.start.time).chain(shift.end.iter().map(|end| &end.time));
+        let break_times = shift.breaks.iter().flat_map(|breaks| breaks.iter()).filter_map(|vehicle_break| {
+            match &vehicle_break.time {
+                VehicleBreakTime::TimeWindow(time) => Some(time),
+                VehicleBreakTime::TimeOffset(_) => None,
+            }
+        });
+        let reload_times = shift
+            .reloads
+            .iter()
+            .flat_map(|reloads| reloads.iter())
+            .flat_map(|reload| reload.times.iter().flat_map(|times| times.iter()));
+
+        place_times.cloned().chain(break_times.chain(reload_times).flat_map(|time| time.iter().cloned()))
+    });
+
+    let offsets = job_times
+        .flat_map(|time| time.iter().cloned())
+        .chain(shift_times)
+        .filter_map(|time| parse_time_offset_safe(&time).ok())
+        .collect::<BTreeSet<_>>();
+
+    let ids = if offsets.len() > 1 { offsets.into_iter().map(format_offset).collect() } else { vec![] };
+
+    create_warning(
+        "W1005",
+        "problem has times with different offsets from UTC",
+        "use the same offset for all times (solution times are reported using offset of the first vehicle \
+         shift start), found offsets",
+        ids,
+    )
+}
+
+fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+
+    format!("{}{:02}:{:02}", sign, offset / 3600, (offset % 3600) / 60)
+}
+
+/// Provides access to routing matrix error codes.
+struct Routing {
+    coord_index: CoordIndex,
+}
+
+impl Routing {
+    /// Checks whether every job task can be reached from vehicle shift start.
+    fn can_reach(&self, ctx: &ValidationContext, job: &Job, vehicle: &VehicleType, shift: &VehicleShift) -> bool {
+        let error_codes = ctx
+            .matrices
+            .and_then(|matrices| matrices.iter().find(|matrix| matrix.profile == vehicle.profile))
+            .and_then(|matrix| matrix.error_codes.as_ref());
+
+        let error_codes = match error_codes {
+            Some(error_codes) => error_codes,
+            None => return true,
+        };
+
+        let size = (error_codes.len() as f64).sqrt().round() as usize;
+        let is_reachable =
+            |from: &Location, to: &Location| match (self.coord_index.get_by_loc(from), self.coord_index.get_by_loc(to))
+            {
+                (Some(from), Some(to)) => error_codes.get(from * size + to).map_or(true, |code| *code == 0),
+                _ => true,
+            };
+
+        ctx.tasks(job)
+            .iter()
+            .all(|task| task.places.iter().any(|place| is_reachable(&shift.start.location, &place.location)))
+    }
+}
+
+fn can_fit_demand(ctx: &ValidationContext, job: &Job, vehicle: &VehicleType) -> bool {
+    let get_demand = |tasks: &Option<Vec<JobTask>>| {
+        tasks.iter().flat_map(|tasks| tasks.iter()).filter_map(|task| task.demand.as_ref()).fold(
+            vec![],
+            |acc: Vec<i32>, demand| {
+                (0..acc.len().max(demand.len()))
+                    .map(|idx| acc.get(idx).cloned().unwrap_or(0) + demand.get(idx).cloned().unwrap_or(0))
+                    .collect()
+            },
+        )
+    };
+
+    let fits = |demand: &Vec<i32>| {
+        demand.iter().enumerate().all(|(idx, value)| *value <= vehicle.capacity.get(idx).cloned().unwrap_or(0))
+    };
+
+    fits(&get_demand(&job.pickups))
+        && fits(&get_demand(&job.deliveries))
+        && ctx.tasks(job).iter().filter_map(|task| task.demand.as_ref()).all(fits)
+}
+
+fn has_skills(job: &Job, vehicle: &VehicleType) -> bool {
+    job.skills.as_ref().map_or(true, |skills| {
+        skills
+            .iter()
+            .all(|skill| vehicle.skills.as_ref().map_or(false, |vehicle_skills| vehicle_skills.contains(skill)))
+    })
+}
+
+fn can_serve_in_time(ctx: &ValidationContext, job: &Job, shift: &VehicleShift) -> bool {
+    let shift_time = match get_time_window(
+        &shift.start.time,
+        &shift.end.as_ref().map_or_else(|| "2200-07-04T00:00:00Z".to_string(), |end| end.time.clone()),
+    ) {
+        Some(shift_time) => shift_time,
+        None => return true,
+    };
+
+    ctx.tasks(job).iter().all(|task| {
+        task.places.iter().any(|place| {
+            place.times.as_ref().map_or(true, |times| {
+                get_time_windows(times).iter().any(|tw| tw.as_ref().map_or(true, |tw| tw.intersects(&shift_time)))
+            })
+        })
+    })
+}
+
+fn get_job_ids<F>(ctx: &ValidationContext, predicate: F) -> Vec<String>
+where
+    F: Fn(&Job) -> bool,
+{
+    ctx.jobs().filter(|job| predicate(job)).map(|job| job.id.clone()).collect()
+}
+
+fn create_warning(code: &str, cause: &str, action: &str, ids: Vec<String>) -> Result<(), FormatError> {
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(code.to_string(), cause.to_string(), format!("{}: '{}'", action, ids.join(", "))))
+    }
+}
+
+/// Analyzes problem feasibility and returns warnings about jobs which cannot be served.
+pub fn analyze_feasibility(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    let routing = Routing { coord_index: CoordIndex::new(ctx.problem) };
+
+    combine_error_results(&[
+        check_w1000_job_demand_fits_capacity(ctx),
+        check_w1001_job_skills_are_available(ctx),
+        check_w1002_job_time_windows_match_shifts(ctx),
+        check_w1003_job_locations_are_reachable(ctx, &routing),
+        check_w1004_relation_jobs_can_be_served(ctx, &routing),
+        check_w1005_times_have_same_offset(ctx),
+    ])
+}
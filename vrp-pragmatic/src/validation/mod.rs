@@ -26,7 +26,10 @@ mod relations;
 use self::relations::validate_relations;
 
 mod routing;
-use self::routing::validate_profiles;
+use self::routing::{validate_matrices, validate_profiles};
+
+mod feasibility;
+use self::feasibility::analyze_feasibility;
 
 impl<'a> ValidationContext<'a> {
     /// Creates an instance of `ValidationContext`.
@@ -46,6 +49,7 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_vehicles(&self).err().into_iter())
             .chain(validate_objectives(&self).err().into_iter())
             .chain(validate_profiles(&self).err().into_iter())
+            .chain(validate_matrices(&self).err().into_iter())
             .chain(validate_relations(&self).err().into_iter())
             .flatten()
             .collect::<Vec<_>>();
@@ -57,6 +61,12 @@ impl<'a> ValidationContext<'a> {
         }
     }
 
+    /// Analyzes problem on set of rules which detect jobs which cannot be served by any vehicle.
+    /// Such problem is still valid, so returned errors should be treated as warnings.
+    pub fn analyze(&self) -> Vec<FormatError> {
+        analyze_feasibility(&self).err().unwrap_or_default()
+    }
+
     /// Gets list of jobs from the problem.
     fn jobs(&self) -> impl Iterator<Item = &Job> {
         self.problem.plan.jobs.iter()
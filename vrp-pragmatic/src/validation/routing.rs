@@ -3,6 +3,7 @@
 mod routing_test;
 
 use super::*;
+use crate::format::CoordIndex;
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -28,7 +29,187 @@ fn check_e1501_empty_profiles(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that routing matrix size matches amount of unique locations in the problem.
+fn check_e1502_matrix_size(ctx: &ValidationContext, size: usize) -> Result<(), FormatError> {
+    let profiles = get_matrix_profiles(ctx, |matrix| {
+        matrix.travel_times.len() != size * size || matrix.distances.len() != size * size
+    });
+
+    create_matrix_error(
+        "E1502",
+        "routing matrix size does not match amount of unique locations",
+        format!("check that matrix has {} travel times and distances for profiles", size * size),
+        profiles,
+    )
+}
+
+/// Checks that routing matrix has no negative travel times or distances.
+fn check_e1503_matrix_negative_values(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let details = get_matrix_details(ctx, |matrix| {
+        let is_negative = |values: &Vec<i64>, idx: usize| values.get(idx).map_or(false, |value| *value < 0);
+
+        (0..matrix.travel_times.len().max(matrix.distances.len()))
+            .filter(|&idx| is_negative(&matrix.travel_times, idx) || is_negative(&matrix.distances, idx))
+            .collect()
+    });
+
+    create_matrix_error(
+        "E1503",
+        "routing matrix has negative values",
+        "correct travel times or distances for profiles and indices".to_string(),
+        details,
+    )
+}
+
+/// Checks that routing matrix error codes length matches matrix size.
+fn check_e1504_matrix_error_codes_size(ctx: &ValidationContext, size: usize) -> Result<(), FormatError> {
+    let profiles = get_matrix_profiles(ctx, |matrix| {
+        matrix.error_codes.as_ref().map_or(false, |error_codes| error_codes.len() != size * size)
+    });
+
+    create_matrix_error(
+        "E1504",
+        "routing matrix error codes size does not match amount of unique locations",
+        format!("check that matrix has {} error codes for profiles", size * size),
+        profiles,
+    )
+}
+
+/// Checks that routing matrix has zero travel times and distances on diagonal.
+fn check_e1505_matrix_non_zero_diagonal(ctx: &ValidationContext, size: usize) -> Result<(), FormatError> {
+    let details = get_matrix_details(ctx, |matrix| {
+        if matrix.travel_times.len() != size * size || matrix.distances.len() != size * size {
+            return vec![];
+        }
+
+        (0..size)
+            .map(|idx| idx * size + idx)
+            .filter(|&idx| matrix.travel_times[idx] != 0 || matrix.distances[idx] != 0)
+            .collect()
+    });
+
+    create_matrix_error(
+        "E1505",
+        "routing matrix has non-zero values on diagonal",
+        "set zero travel times and distances for profiles and indices".to_string(),
+        details,
+    )
+}
+
+/// Checks that locations are not represented by geocoordinates and indices at the same time.
+fn check_e1506_mixed_location_types(coord_index: &CoordIndex) -> Result<(), FormatError> {
+    if coord_index.has_indices() && coord_index.has_coordinates() {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "mixed location types".to_string(),
+            "use either geocoordinates or indices for all locations in the problem".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that routing matrices are specified when locations are represented by indices.
+fn check_e1507_index_locations_without_matrices(
+    ctx: &ValidationContext,
+    coord_index: &CoordIndex,
+) -> Result<(), FormatError> {
+    if ctx.matrices.is_none() && coord_index.has_indices() {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "routing matrices are required for index locations".to_string(),
+            "specify routing matrices or use geocoordinates for locations".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns expected routing matrix size. When locations are represented by indices, matrix can
+/// be bigger than amount of unique locations, but it should contain all referenced indices.
+fn get_matrix_size(ctx: &ValidationContext, coord_index: &CoordIndex) -> usize {
+    let locations = coord_index.unique();
+
+    if coord_index.has_indices() {
+        let max_index = locations.iter().filter_map(|location| location.as_index()).max().unwrap_or(0);
+        let dimension =
+            get_matrices(ctx).next().map_or(0, |matrix| (matrix.travel_times.len() as f64).sqrt().round() as usize);
+
+        dimension.max(max_index + 1)
+    } else {
+        locations.len()
+    }
+}
+
+fn get_matrix_profiles<F>(ctx: &ValidationContext, predicate: F) -> Vec<String>
+where
+    F: Fn(&Matrix) -> bool,
+{
+    get_matrices(ctx).filter(|matrix| predicate(matrix)).map(|matrix| matrix.profile.clone()).collect()
+}
+
+/// Max amount of matrix indices listed per profile in error details.
+const MAX_DETAILS_INDICES: usize = 10;
+
+fn get_matrix_details<F>(ctx: &ValidationContext, get_indices: F) -> Vec<String>
+where
+    F: Fn(&Matrix) -> Vec<usize>,
+{
+    get_matrices(ctx)
+        .filter_map(|matrix| {
+            let indices = get_indices(matrix);
+            if indices.is_empty() {
+                None
+            } else {
+                let total = indices.len();
+                let indices = indices.iter().take(MAX_DETAILS_INDICES).map(|idx| idx.to_string()).collect::<Vec<_>>();
+                let indices = if total > MAX_DETAILS_INDICES {
+                    format!("{}, ... {} in total", indices.join(", "), total)
+                } else {
+                    indices.join(", ")
+                };
+
+                Some(format!("{} [{}]", matrix.profile, indices))
+            }
+        })
+        .collect()
+}
+
+fn get_matrices<'a>(ctx: &'a ValidationContext) -> impl Iterator<Item = &'a Matrix> {
+    ctx.matrices.iter().flat_map(|matrices| matrices.iter())
+}
+
+fn create_matrix_error(code: &str, cause: &str, action: String, details: Vec<String>) -> Result<(), FormatError> {
+    if details.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(code.to_string(), cause.to_string(), format!("{}: '{}'", action, details.join(", "))))
+    }
+}
+
 /// Validates profiles from the fleet.
 pub fn validate_profiles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[check_e1500_duplicated_profiles(ctx), check_e1501_empty_profiles(ctx)])
 }
+
+/// Validates routing matrices against the problem.
+pub fn validate_matrices(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
+    let coord_index = CoordIndex::new(ctx.problem);
+
+    if ctx.matrices.is_none() {
+        return combine_error_results(&[
+            check_e1506_mixed_location_types(&coord_index),
+            check_e1507_index_locations_without_matrices(ctx, &coord_index),
+        ]);
+    }
+
+    let size = get_matrix_size(ctx, &coord_index);
+
+    combine_error_results(&[
+        check_e1506_mixed_location_types(&coord_index),
+        check_e1502_matrix_size(ctx, size),
+        check_e1503_matrix_negative_values(ctx),
+        check_e1504_matrix_error_codes_size(ctx, size),
+        check_e1505_matrix_non_zero_diagonal(ctx, size),
+    ])
+}
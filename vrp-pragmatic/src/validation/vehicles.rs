@@ -3,6 +3,7 @@
 mod vehicles_test;
 
 use super::*;
+use crate::format::CoordIndex;
 use crate::validation::common::get_time_windows;
 use std::ops::Deref;
 use vrp_core::models::common::TimeWindow;
@@ -162,6 +163,117 @@ fn check_e1305_vehicle_limit_area_is_correct(ctx: &ValidationContext) -> Result<
     }
 }
 
+/// Checks that vehicle costs are not negative.
+fn check_e1306_vehicle_costs_are_not_negative(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            let costs = &vehicle.costs;
+            vec![costs.fixed, Some(costs.distance), Some(costs.time), costs.waiting, costs.service]
+                .into_iter()
+                .any(|cost| cost.map_or(false, |cost| cost < 0.))
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1306".to_string(),
+            "invalid vehicle costs".to_string(),
+            format!("ensure that all vehicle costs are not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle parking time is not negative.
+fn check_e1307_vehicle_parking_is_not_negative(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.parking.map_or(false, |parking| parking < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "invalid vehicle parking".to_string(),
+            format!("ensure that vehicle parking time is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle service factor is positive.
+fn check_e1308_vehicle_service_factor_is_positive(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.service_factor.map_or(false, |factor| factor <= 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "invalid vehicle service factor".to_string(),
+            format!("ensure that vehicle service factor is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle speed factor is positive.
+fn check_e1309_vehicle_speed_factor_is_positive(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.speed_factor.map_or(false, |factor| factor <= 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle speed factor".to_string(),
+            format!("ensure that vehicle speed factor is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle area restrictions are not used with locations represented by indices.
+fn check_e1310_vehicle_limit_area_with_index_locations(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_areas = |vehicle: &&VehicleType| vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).is_some();
+    let has_index_points = |vehicle: &&VehicleType| {
+        vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |areas| {
+            areas.iter().flat_map(|area| area.iter()).any(|location| location.as_index().is_some())
+        })
+    };
+    let has_index_locations = CoordIndex::new(ctx.problem).has_indices();
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| has_areas(vehicle) && (has_index_locations || has_index_points(vehicle)))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "allowed areas cannot be used with index locations".to_string(),
+            format!(
+                "use geocoordinates for all locations or remove allowed areas, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -208,5 +320,10 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1303_vehicle_breaks_time_is_correct(ctx),
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
+        check_e1306_vehicle_costs_are_not_negative(ctx),
+        check_e1307_vehicle_parking_is_not_negative(ctx),
+        check_e1308_vehicle_service_factor_is_positive(ctx),
+        check_e1309_vehicle_speed_factor_is_positive(ctx),
+        check_e1310_vehicle_limit_area_with_index_locations(ctx),
     ])
 }
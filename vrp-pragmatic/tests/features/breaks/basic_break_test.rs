@@ -38,7 +38,10 @@ fn can_assign_break_between_jobs() {
                 cost: 54.,
                 distance: 20,
                 duration: 24,
-                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -90,7 +93,10 @@ fn can_assign_break_between_jobs() {
                     cost: 54.,
                     distance: 20,
                     duration: 24,
-                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -39,7 +39,10 @@ fn can_assign_break_using_second_location() {
                 cost: 74.,
                 distance: 30,
                 duration: 34,
-                times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 30., driving: 30., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,7 +94,10 @@ fn can_assign_break_using_second_location() {
                     cost: 74.,
                     distance: 30,
                     duration: 34,
-                    times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 30., driving: 30., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
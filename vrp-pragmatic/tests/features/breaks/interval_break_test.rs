@@ -37,7 +37,10 @@ fn can_assign_interval_break_between_jobs() {
                 cost: 74.,
                 distance: 30,
                 duration: 34,
-                times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 30., driving: 30., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -82,6 +85,8 @@ fn can_assign_interval_break_between_jobs() {
                                 job_tag: None,
                             }
                         ],
+                        latest_arrival: None,
+                        slack: None
                     },
                     create_stop_with_activity(
                         "job2",
@@ -104,7 +109,10 @@ fn can_assign_interval_break_between_jobs() {
                     cost: 74.,
                     distance: 30,
                     duration: 34,
-                    times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 30., driving: 30., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -160,7 +168,10 @@ fn can_assign_interval_break_with_reload() {
                 cost: 139.,
                 distance: 60,
                 duration: 69,
-                times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 60., driving: 60., waiting: 0., serving: 9. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -205,6 +216,8 @@ fn can_assign_interval_break_with_reload() {
                                 job_tag: None,
                             }
                         ],
+                        latest_arrival: None,
+                        slack: None
                     },
                     create_stop_with_activity(
                         "job2",
@@ -251,7 +264,10 @@ fn can_assign_interval_break_with_reload() {
                     cost: 139.,
                     distance: 60,
                     duration: 69,
-                    times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 60., driving: 60., waiting: 0., serving: 9. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
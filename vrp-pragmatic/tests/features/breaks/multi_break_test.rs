@@ -44,7 +44,10 @@ fn can_use_two_breaks() {
                 cost: 412.,
                 distance: 198,
                 duration: 204,
-                times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 198., driving: 198., waiting: 0., serving: 6. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -105,6 +108,8 @@ fn can_use_two_breaks() {
                                 job_tag: None,
                             }
                         ],
+                        latest_arrival: None,
+                        slack: None
                     },
                     create_stop_with_activity(
                         "arrival",
@@ -119,7 +124,10 @@ fn can_use_two_breaks() {
                     cost: 412.,
                     distance: 198,
                     duration: 204,
-                    times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                    times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 198., driving: 198., waiting: 0., serving: 6. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -54,7 +54,10 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 cost: 26.,
                 distance: 6,
                 duration: 10,
-                times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -106,7 +109,10 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     cost: 26.,
                     distance: 6,
                     duration: 10,
-                    times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -134,7 +140,10 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 cost: 26.,
                 distance: 6,
                 duration: 10,
-                times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -186,7 +195,10 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     cost: 26.,
                     distance: 6,
                     duration: 10,
-                    times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -45,7 +45,10 @@ fn can_skip_break_when_vehicle_not_used() {
                 cost: 52.,
                 distance: 20,
                 duration: 22,
-                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -89,7 +92,10 @@ fn can_skip_break_when_vehicle_not_used() {
                     cost: 52.,
                     distance: 20,
                     duration: 22,
-                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 2. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -132,7 +138,10 @@ fn can_skip_break_when_jobs_completed() {
                 cost: 24.,
                 distance: 2,
                 duration: 12,
-                times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 10. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -168,7 +177,10 @@ fn can_skip_break_when_jobs_completed() {
                     cost: 24.,
                     distance: 2,
                     duration: 12,
-                    times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 10. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![UnassignedJob {
@@ -176,6 +188,7 @@ fn can_skip_break_when_jobs_completed() {
                 reasons: vec![UnassignedJobReason {
                     code: 2,
                     description: "cannot be visited within time window".to_string(),
+                    vehicle_id: None
                 }],
             }],
             extras: None,
@@ -224,7 +237,10 @@ fn can_skip_second_break_when_jobs_completed() {
                 cost: 54.,
                 distance: 20,
                 duration: 24,
-                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -276,7 +292,10 @@ fn can_skip_second_break_when_jobs_completed() {
                     cost: 54.,
                     distance: 20,
                     duration: 24,
-                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
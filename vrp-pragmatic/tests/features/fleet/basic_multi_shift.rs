@@ -48,7 +48,10 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 cost: 102.,
                 distance: 40,
                 duration: 42,
-                times: Timing { driving: 40, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 40, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 20., distance: 40., driving: 40., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![
                 Tour {
@@ -85,7 +88,16 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         cost: 51.,
                         distance: 20,
                         duration: 21,
-                        times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 20.,
+                            driving: 20.,
+                            waiting: 0.,
+                            serving: 1.
+                        }),
+                        objectives: None,
                     },
                 },
                 Tour {
@@ -122,7 +134,16 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         cost: 51.,
                         distance: 20,
                         duration: 21,
-                        times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 20.,
+                            driving: 20.,
+                            waiting: 0.,
+                            serving: 1.
+                        }),
+                        objectives: None,
                     },
                 }
             ],
@@ -26,7 +26,10 @@ fn can_use_vehicle_with_open_end() {
                 cost: 13.,
                 distance: 1,
                 duration: 2,
-                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 1., driving: 1., waiting: 0., serving: 1. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -54,7 +57,10 @@ fn can_use_vehicle_with_open_end() {
                     cost: 13.,
                     distance: 1,
                     duration: 2,
-                    times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 1., driving: 1., waiting: 0., serving: 1. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
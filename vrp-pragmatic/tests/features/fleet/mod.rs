@@ -1,4 +1,9 @@
 mod basic_multi_shift;
 mod basic_open_end;
 mod multi_dimens;
+mod parking_per_stop;
+mod service_factor;
+mod speed_factor;
+mod unassigned_explanation;
 mod unreachable_jobs;
+mod waiting_service_costs;
@@ -33,7 +33,10 @@ fn can_use_two_dimensions() {
                 cost: 16.,
                 distance: 2,
                 duration: 4,
-                times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -69,7 +72,10 @@ fn can_use_two_dimensions() {
                     cost: 16.,
                     distance: 2,
                     duration: 4,
-                    times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 2. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -103,14 +109,18 @@ fn can_unassign_due_to_dimension_mismatch() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 3,
-                    description: "does not fit into any vehicle due to capacity".to_string()
+                    description: "does not fit into any vehicle due to capacity".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
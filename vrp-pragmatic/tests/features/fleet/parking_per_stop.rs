@@ -0,0 +1,35 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_apply_parking_time_once_per_stop() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![10., 0.]),
+                create_delivery_job("job2", vec![10., 0.]),
+                create_delivery_job("job3", vec![20., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { parking: Some(5.), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_details(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours[0].stops.len(), 4);
+    assert_eq!(solution.statistic.times, Timing { driving: 40, serving: 3, waiting: 0, break_time: 0, parking: 10 });
+    assert_eq!(solution.statistic.duration, 53);
+    assert_eq!(
+        solution.statistic.costs,
+        Some(CostStatistic { fixed: 10., distance: 40., driving: 40., waiting: 0., serving: 13. })
+    );
+    assert_eq!(solution.statistic.cost, 103.);
+}
@@ -0,0 +1,33 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_vehicle_service_factor() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 100)], 2.),
+                create_delivery_job_with_times("job2", vec![10., 0.], vec![(0, 100)], 2.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                type_id: "truck".to_string(),
+                vehicle_ids: vec!["truck_1".to_string()],
+                service_factor: Some(3.),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.statistic.times, Timing { driving: 20, serving: 12, waiting: 0, break_time: 0, parking: 0 });
+    assert_eq!(solution.statistic.cost, 62.);
+}
@@ -0,0 +1,35 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_vehicle_speed_factor_on_shared_profile() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Any,
+                jobs: vec!["job1".to_string(), "job2".to_string()],
+                vehicle_id: "fast_1".to_string(),
+                shift_index: None,
+            }]),
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("slow"),
+                VehicleType { speed_factor: Some(2.), ..create_default_vehicle("fast") },
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "fast_1");
+    assert_eq!(solution.statistic.distance, 40);
+    assert_eq!(solution.statistic.times, Timing { driving: 20, serving: 2, waiting: 0, break_time: 0, parking: 0 });
+}
@@ -0,0 +1,48 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_explain_unassigned_job_per_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills("job1", vec![100., 0.], vec!["unique_skill".to_string()])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    skills: Some(vec!["unique_skill".to_string()]),
+                    limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, allowed_areas: None }),
+                    ..create_default_vehicle("vehicle_with_skill")
+                },
+                create_default_vehicle("vehicle_without_skill"),
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![
+                UnassignedJobReason {
+                    code: 101,
+                    description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
+                    vehicle_id: Some("vehicle_with_skill_1".to_string())
+                },
+                UnassignedJobReason {
+                    code: 1,
+                    description: "cannot serve required skill".to_string(),
+                    vehicle_id: Some("vehicle_without_skill_1".to_string())
+                }
+            ]
+        }]
+    );
+}
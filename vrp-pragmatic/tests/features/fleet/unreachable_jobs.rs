@@ -26,12 +26,19 @@ fn can_use_vehicle_with_open_end() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }]
+                reasons: vec![UnassignedJobReason {
+                    code: 100,
+                    description: "location unreachable".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
+                }]
             }],
             extras: None,
         }
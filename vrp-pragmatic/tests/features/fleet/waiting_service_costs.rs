@@ -0,0 +1,43 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_separate_waiting_and_service_costs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(30, 40)], 1.),
+                create_delivery_job("job2", vec![10., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: Some(0.5), service: Some(3.) },
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_details(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.statistic.times, Timing { driving: 20, serving: 2, waiting: 14, break_time: 0, parking: 0 });
+    assert_eq!(
+        solution.statistic.costs,
+        Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 7., serving: 6. })
+    );
+    assert_eq!(solution.statistic.cost, 63.);
+    assert_eq!(
+        solution
+            .statistic
+            .objectives
+            .as_ref()
+            .and_then(|objectives| objectives.secondary.iter().find(|value| value.name == "minimize-cost"))
+            .map(|value| value.value),
+        Some(solution.statistic.cost)
+    );
+}
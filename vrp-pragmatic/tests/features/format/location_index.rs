@@ -0,0 +1,50 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_index(id: &str, index: usize) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { location: Location::new_reference(index), ..create_job_place(vec![0., 0.]) }],
+            ..create_task(vec![0., 0.])
+        }]),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_use_locations_referenced_by_matrix_index() {
+    let depot = VehiclePlace { time: format_time(0.), location: Location::new_reference(0) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job_with_index("job1", 1), create_job_with_index("job2", 2)], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: depot.clone(),
+                    end: Some(VehiclePlace { time: format_time(1000.), ..depot }),
+                    breaks: None,
+                    reloads: None,
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    // NOTE matrix has an extra location which is not referenced by the problem
+    let matrix = create_matrix(vec![
+        0, 5, 7, 100, //
+        5, 0, 3, 100, //
+        7, 3, 0, 100, //
+        100, 100, 100, 0,
+    ]);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.statistic.distance, 15);
+    assert_eq!(solution.tours[0].stops.len(), 4);
+    assert!(solution.tours[0].stops.iter().all(|stop| stop.location.as_index().is_some()));
+}
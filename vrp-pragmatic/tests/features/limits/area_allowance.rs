@@ -36,14 +36,18 @@ fn can_limit_by_area() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 106,
-                    description: "cannot be assigned due to area constraint".to_string()
+                    description: "cannot be assigned due to area constraint".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
@@ -18,8 +18,8 @@ fn can_limit_by_max_distance() {
     let matrix = Matrix {
         profile: "car".to_owned(),
         timestamp: None,
-        travel_times: vec![1, 1, 1, 1],
-        distances: vec![1, 100, 100, 1],
+        travel_times: vec![0, 1, 1, 0],
+        distances: vec![0, 100, 100, 0],
         error_codes: Option::None,
     };
 
@@ -32,14 +32,18 @@ fn can_limit_by_max_distance() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 101,
-                    description: "cannot be assigned due to max distance constraint of vehicle".to_string()
+                    description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
@@ -18,8 +18,8 @@ fn can_limit_one_job_by_shift_time() {
     let matrix = Matrix {
         profile: "car".to_owned(),
         timestamp: None,
-        travel_times: vec![1, 100, 100, 1],
-        distances: vec![1, 1, 1, 1],
+        travel_times: vec![0, 100, 100, 0],
+        distances: vec![0, 1, 1, 0],
         error_codes: Option::None,
     };
 
@@ -32,14 +32,18 @@ fn can_limit_one_job_by_shift_time() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 102,
-                    description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                    description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
@@ -80,7 +84,10 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 cost: 52.,
                 distance: 6,
                 duration: 36,
-                times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 30. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -132,7 +139,10 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     cost: 52.,
                     distance: 6,
                     duration: 36,
-                    times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 30. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![
@@ -140,14 +150,16 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     job_id: "job4".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: 102,
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                        description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                        vehicle_id: Some("my_vehicle_1".to_string())
                     }]
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: 102,
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                        description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                        vehicle_id: Some("my_vehicle_1".to_string())
                     }]
                 }
             ],
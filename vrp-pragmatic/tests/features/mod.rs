@@ -2,6 +2,7 @@
 
 mod breaks;
 mod fleet;
+mod format;
 mod limits;
 mod multjob;
 mod pickdev;
@@ -33,7 +33,10 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 cost: 46.,
                 distance: 16,
                 duration: 20,
-                times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 16., driving: 16., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,7 +99,10 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     cost: 46.,
                     distance: 16,
                     duration: 20,
-                    times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 16., driving: 16., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -133,7 +139,10 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 cost: 37.,
                 distance: 12,
                 duration: 15,
-                times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 12., driving: 12., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -188,7 +197,10 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     cost: 37.,
                     distance: 12,
                     duration: 15,
-                    times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 12., driving: 12., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -37,7 +37,10 @@ fn can_assign_replacement_job() {
                 cost: 21.,
                 distance: 4,
                 duration: 7,
-                times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -89,7 +92,10 @@ fn can_assign_replacement_job() {
                     cost: 21.,
                     distance: 4,
                     duration: 7,
-                    times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
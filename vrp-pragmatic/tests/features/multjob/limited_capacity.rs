@@ -37,7 +37,10 @@ fn can_handle_limited_capacity() {
                 cost: 88.,
                 distance: 36,
                 duration: 42,
-                times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 36., driving: 36., waiting: 0., serving: 6. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -119,7 +122,10 @@ fn can_handle_limited_capacity() {
                     cost: 88.,
                     distance: 36,
                     duration: 42,
-                    times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 36., driving: 36., waiting: 0., serving: 6. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
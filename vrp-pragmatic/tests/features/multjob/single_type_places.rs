@@ -30,7 +30,10 @@ fn can_use_only_deliveries_as_static_demand() {
                 cost: 29.,
                 distance: 8,
                 duration: 11,
-                times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 8., driving: 8., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -68,7 +71,10 @@ fn can_use_only_deliveries_as_static_demand() {
                     cost: 29.,
                     distance: 8,
                     duration: 11,
-                    times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 8., driving: 8., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -107,7 +113,10 @@ fn can_use_only_pickups_as_static_demand() {
                 cost: 33.,
                 distance: 10,
                 duration: 13,
-                times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 10., driving: 10., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -153,7 +162,10 @@ fn can_use_only_pickups_as_static_demand() {
                     cost: 33.,
                     distance: 10,
                     duration: 13,
-                    times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 10., driving: 10., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -30,14 +30,18 @@ fn can_unassign_multi_job_due_to_capacity() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "multi".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 3,
-                    description: "does not fit into any vehicle due to capacity".to_string()
+                    description: "does not fit into any vehicle due to capacity".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
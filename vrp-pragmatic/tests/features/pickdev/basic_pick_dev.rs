@@ -20,7 +20,10 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 cost: 20.,
                 distance: 4,
                 duration: 6,
-                times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -64,7 +67,10 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     cost: 20.,
                     distance: 4,
                     duration: 6,
-                    times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 0., serving: 2. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -27,7 +27,10 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 cost: 30.,
                 distance: 8,
                 duration: 12,
-                times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 8., driving: 8., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -87,7 +90,10 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     cost: 30.,
                     distance: 8,
                     duration: 12,
-                    times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 8., driving: 8., waiting: 0., serving: 4. }),
+                    objectives: None,
                 }
             }],
             unassigned: vec![],
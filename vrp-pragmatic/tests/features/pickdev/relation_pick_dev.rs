@@ -37,7 +37,10 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 cost: 114.,
                 distance: 50,
                 duration: 54,
-                times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 50., driving: 50., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,7 +100,10 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     cost: 114.,
                     distance: 50,
                     duration: 54,
-                    times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 50., driving: 50., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -33,7 +33,10 @@ fn can_follow_priorities() {
                 cost: 53.,
                 distance: 20,
                 duration: 23,
-                times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -85,7 +88,10 @@ fn can_follow_priorities() {
                     cost: 53.,
                     distance: 20,
                     duration: 23,
-                    times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -39,7 +39,10 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 cost: 19.,
                 distance: 3,
                 duration: 6,
-                times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 3., driving: 3., waiting: 0., serving: 3. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -83,7 +86,10 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     cost: 19.,
                     distance: 3,
                     duration: 6,
-                    times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 3., driving: 3., waiting: 0., serving: 3. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -0,0 +1,53 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_job_ids(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_keep_frozen_tour_when_new_jobs_added() {
+    let create_problem = |jobs: Vec<Job>| Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let jobs = vec![
+        create_delivery_job("job1", vec![1., 0.]),
+        create_delivery_job("job2", vec![2., 0.]),
+        create_delivery_job("job3", vec![3., 0.]),
+        create_delivery_job("job4", vec![4., 0.]),
+    ];
+    let problem = create_problem(jobs.clone());
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+    let frozen_vehicle_id = solution.tours.first().unwrap().vehicle_id.clone();
+    let frozen_job_ids = get_job_ids(&solution, frozen_vehicle_id.as_str());
+
+    let problem = merge_relations(
+        create_problem(jobs.into_iter().chain(std::iter::once(create_delivery_job("job5", vec![1., 1.]))).collect()),
+        create_relations(&solution, RelationType::Strict, Some(&[frozen_vehicle_id.clone()])),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution, frozen_vehicle_id.as_str()), frozen_job_ids);
+    assert!(solution.unassigned.is_empty());
+    assert!(!frozen_job_ids.contains(&"job5".to_string()));
+    assert!(solution.tours.iter().flat_map(|tour| tour.stops.iter()).any(|stop| stop.activities[0].job_id == "job5"));
+}
@@ -44,7 +44,10 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 cost: 53.,
                 distance: 18,
                 duration: 25,
-                times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 18., driving: 18., waiting: 0., serving: 7. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -128,7 +131,10 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     cost: 53.,
                     distance: 18,
                     duration: 25,
-                    times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 18., driving: 18., waiting: 0., serving: 7. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -44,7 +44,10 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 cost: 61.,
                 distance: 22,
                 duration: 29,
-                times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 22., driving: 22., waiting: 0., serving: 7. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -128,7 +131,10 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     cost: 61.,
                     distance: 22,
                     duration: 29,
-                    times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 22., driving: 22., waiting: 0., serving: 7. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -200,7 +206,10 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 cost: 80.,
                 distance: 26,
                 duration: 34,
-                times: Timing { driving: 26, serving: 8, waiting: 0, break_time: 0 },
+                times: Timing { driving: 26, serving: 8, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 20., distance: 26., driving: 26., waiting: 0., serving: 8. }),
+                objectives: None,
             },
             tours: vec![
                 Tour {
@@ -253,7 +262,16 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         cost: 40.,
                         distance: 13,
                         duration: 17,
-                        times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 13.,
+                            driving: 13.,
+                            waiting: 0.,
+                            serving: 4.
+                        }),
+                        objectives: None,
                     },
                 },
                 Tour {
@@ -306,7 +324,16 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         cost: 40.,
                         distance: 13,
                         duration: 17,
-                        times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 13.,
+                            driving: 13.,
+                            waiting: 0.,
+                            serving: 4.
+                        }),
+                        objectives: None,
                     },
                 }
             ],
@@ -54,7 +54,10 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 cost: 114.,
                 distance: 42,
                 duration: 52,
-                times: Timing { driving: 42, serving: 10, waiting: 0, break_time: 0 },
+                times: Timing { driving: 42, serving: 10, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 20., distance: 42., driving: 42., waiting: 0., serving: 10. }),
+                objectives: None,
             },
             tours: vec![
                 Tour {
@@ -123,7 +126,16 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         cost: 59.,
                         distance: 22,
                         duration: 27,
-                        times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 22.,
+                            driving: 22.,
+                            waiting: 0.,
+                            serving: 5.
+                        }),
+                        objectives: None,
                     },
                 },
                 Tour {
@@ -192,7 +204,16 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         cost: 55.,
                         distance: 20,
                         duration: 25,
-                        times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 20.,
+                            driving: 20.,
+                            waiting: 0.,
+                            serving: 5.
+                        }),
+                        objectives: None,
                     },
                 }
             ],
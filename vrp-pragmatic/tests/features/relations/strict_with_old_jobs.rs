@@ -52,7 +52,10 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 cost: 96.,
                 distance: 34,
                 duration: 42,
-                times: Timing { driving: 34, serving: 8, waiting: 0, break_time: 0 },
+                times: Timing { driving: 34, serving: 8, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 20., distance: 34., driving: 34., waiting: 0., serving: 8. }),
+                objectives: None,
             },
             tours: vec![
                 Tour {
@@ -113,7 +116,16 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         cost: 54.,
                         distance: 20,
                         duration: 24,
-                        times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 20.,
+                            driving: 20.,
+                            waiting: 0.,
+                            serving: 4.
+                        }),
+                        objectives: None,
                     },
                 },
                 Tour {
@@ -174,7 +186,16 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         cost: 42.,
                         distance: 14,
                         duration: 18,
-                        times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 14.,
+                            driving: 14.,
+                            waiting: 0.,
+                            serving: 4.
+                        }),
+                        objectives: None,
                     },
                 }
             ],
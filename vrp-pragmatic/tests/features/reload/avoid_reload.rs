@@ -48,7 +48,10 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 cost: 46.,
                 distance: 16,
                 duration: 20,
-                times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 16., driving: 16., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -111,7 +114,10 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                     cost: 46.,
                     distance: 16,
                     duration: 20,
-                    times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 16., driving: 16., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -22,8 +22,7 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                     job_id: "job3".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: 3,
-                        description: "does not fit into any vehicle due to capacity".to_string()
-                    }]
+                        description: "does not fit into any vehicle due to capacity".to_string(), vehicle_id: Some("my_vehicle_1".to_string()) }]
                 }
              ]),
 }
@@ -62,7 +61,10 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 cost: 26.,
                 distance: 6,
                 duration: 10,
-                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -114,7 +116,10 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     cost: 26.,
                     distance: 6,
                     duration: 10,
-                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned,
@@ -55,7 +55,10 @@ fn can_use_reloads_with_different_locations() {
                 cost: 95.,
                 distance: 38,
                 duration: 47,
-                times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 38., driving: 38., waiting: 0., serving: 9. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -141,7 +144,10 @@ fn can_use_reloads_with_different_locations() {
                     cost: 95.,
                     distance: 38,
                     duration: 47,
-                    times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 38., driving: 38., waiting: 0., serving: 9. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
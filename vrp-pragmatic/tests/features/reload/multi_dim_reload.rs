@@ -44,7 +44,10 @@ fn can_use_multi_dim_capacity() {
                 cost: 26.,
                 distance: 6,
                 duration: 10,
-                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,7 +99,10 @@ fn can_use_multi_dim_capacity() {
                     cost: 26.,
                     distance: 6,
                     duration: 10,
-                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
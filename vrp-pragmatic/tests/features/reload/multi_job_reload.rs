@@ -51,7 +51,10 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 cost: 46.,
                 distance: 14,
                 duration: 22,
-                times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 14., driving: 14., waiting: 0., serving: 8. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -138,7 +141,10 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     cost: 46.,
                     distance: 14,
                     duration: 22,
-                    times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 14., driving: 14., waiting: 0., serving: 8. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -164,18 +170,18 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003, waiting: None, service: None },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
                             times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
+                            location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2620.0,
                             tag: None,
                         },
                         VehicleReload {
                             times: None,
-                            location: Location { lat: 0.0, lng: 0.0 },
+                            location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2874.0,
                             tag: None,
                         },
@@ -42,7 +42,10 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 cost: 26.,
                 distance: 6,
                 duration: 10,
-                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -94,7 +97,10 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     cost: 26.,
                     distance: 6,
                     duration: 10,
-                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 4. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
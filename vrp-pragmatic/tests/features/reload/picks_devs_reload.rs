@@ -47,7 +47,10 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 cost: 28.,
                 distance: 6,
                 duration: 12,
-                times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 6. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -115,14 +118,18 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     cost: 28.,
                     distance: 6,
                     duration: 12,
-                    times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 6., driving: 6., waiting: 0., serving: 6. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![UnassignedJob {
                 job_id: "d3".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 3,
-                    description: "does not fit into any vehicle due to capacity".to_string()
+                    description: "does not fit into any vehicle due to capacity".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }],
             }],
             extras: None,
@@ -35,7 +35,10 @@ fn can_wait_for_job_start() {
                 cost: 47.,
                 distance: 18,
                 duration: 19,
-                times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 18., driving: 18., waiting: 0., serving: 1. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -71,7 +74,10 @@ fn can_wait_for_job_start() {
                     cost: 47.,
                     distance: 18,
                     duration: 19,
-                    times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 18., driving: 18., waiting: 0., serving: 1. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -26,12 +26,19 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                 cost: 0.,
                 distance: 0,
                 duration: 0,
-                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 0., distance: 0., driving: 0., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![],
             unassigned: vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }]
+                reasons: vec![UnassignedJobReason {
+                    code: 1,
+                    description: "cannot serve required skill".to_string(),
+                    vehicle_id: Some("vehicle_without_skill_1".to_string())
+                }]
             }],
             extras: None,
         }
@@ -29,7 +29,10 @@ fn can_use_multiple_times() {
                 cost: 240.,
                 distance: 100,
                 duration: 130,
-                times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 100., driving: 100., waiting: 30., serving: 0. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,7 +100,10 @@ fn can_use_multiple_times() {
                     cost: 240.,
                     distance: 100,
                     duration: 130,
-                    times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                    times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 100., driving: 100., waiting: 30., serving: 0. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
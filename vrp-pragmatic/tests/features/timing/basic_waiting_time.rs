@@ -26,7 +26,10 @@ fn can_wait_for_job_start() {
                 cost: 26.,
                 distance: 4,
                 duration: 12,
-                times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 8., serving: 0. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,7 +73,10 @@ fn can_wait_for_job_start() {
                     cost: 26.,
                     distance: 4,
                     duration: 12,
-                    times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                    times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 4., driving: 4., waiting: 8., serving: 0. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -100,7 +106,10 @@ fn can_skip_initial_waiting() {
                 cost: 24.,
                 distance: 2,
                 duration: 12,
-                times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 10. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -136,7 +145,10 @@ fn can_skip_initial_waiting() {
                     cost: 24.,
                     distance: 2,
                     duration: 12,
-                    times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 2., driving: 2., waiting: 0., serving: 10. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -29,7 +29,10 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 cost: 170.,
                 distance: 80,
                 duration: 80,
-                times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 80., driving: 80., waiting: 0., serving: 0. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -89,14 +92,18 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     cost: 170.,
                     distance: 80,
                     duration: 80,
-                    times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 80., driving: 80., waiting: 0., serving: 0. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![UnassignedJob {
                 job_id: "job5".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: 2,
-                    description: "cannot be visited within time window".to_string()
+                    description: "cannot be visited within time window".to_string(),
+                    vehicle_id: Some("my_vehicle_1".to_string())
                 }]
             }],
             extras: None,
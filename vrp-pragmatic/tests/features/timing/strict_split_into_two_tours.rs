@@ -35,7 +35,10 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 cost: 360.,
                 distance: 140,
                 duration: 200,
-                times: Timing { driving: 140, serving: 50, waiting: 10, break_time: 0 },
+                times: Timing { driving: 140, serving: 50, waiting: 10, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 20., distance: 140., driving: 140., waiting: 10., serving: 50. }),
+                objectives: None,
             },
             tours: vec![
                 Tour {
@@ -88,7 +91,16 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         cost: 250.,
                         distance: 100,
                         duration: 140,
-                        times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0 },
+                        times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 100.,
+                            driving: 100.,
+                            waiting: 10.,
+                            serving: 30.,
+                        }),
+                        objectives: None,
                     },
                 },
                 Tour {
@@ -133,7 +145,16 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         cost: 110.,
                         distance: 40,
                         duration: 60,
-                        times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0 },
+                        times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0, parking: 0 },
+                        gap: None,
+                        costs: Some(CostStatistic {
+                            fixed: 10.,
+                            distance: 40.,
+                            driving: 40.,
+                            waiting: 0.,
+                            serving: 20.,
+                        }),
+                        objectives: None,
                     },
                 },
             ],
@@ -0,0 +1,58 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_use_time_offset_of_first_vehicle_shift_in_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: Some(vec![vec!["1970-01-01T00:00:30Z".to_string(), "1970-01-01T00:00:40Z".to_string()]]),
+                        ..create_job_place(vec![10., 0.])
+                    }],
+                    demand: Some(vec![1]),
+                    tag: None,
+                }]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: VehiclePlace {
+                        time: "1970-01-01T02:00:00+02:00".to_string(),
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    end: Some(VehiclePlace {
+                        time: "1970-01-01T02:16:40+02:00".to_string(),
+                        location: vec![0., 0.].to_loc(),
+                    }),
+                    breaks: None,
+                    reloads: None,
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .map(|stop| (stop.time.arrival.as_str(), stop.time.departure.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("1970-01-01T02:00:00+02:00", "1970-01-01T02:00:20+02:00"),
+            ("1970-01-01T02:00:30+02:00", "1970-01-01T02:00:31+02:00"),
+            ("1970-01-01T02:00:41+02:00", "1970-01-01T02:00:41+02:00"),
+        ]
+    );
+}
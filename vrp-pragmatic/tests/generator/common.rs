@@ -15,10 +15,10 @@ prop_compose! {
     /// Generates location inside given bounding box.
     pub fn generate_location(bounding_box: &(Location, Location))
     (
-     lat in bounding_box.0.lat..bounding_box.1.lat,
-     lng in bounding_box.0.lng..bounding_box.1.lng
+     lat in bounding_box.0.to_lat_lng().0..bounding_box.1.to_lat_lng().0,
+     lng in bounding_box.0.to_lat_lng().1..bounding_box.1.to_lat_lng().1
     ) -> Location {
-        Location { lat, lng }
+        Location::Coordinate { lat, lng }
     }
 }
 
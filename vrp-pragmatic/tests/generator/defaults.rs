@@ -8,7 +8,7 @@ use crate::{format_time, parse_time};
 pub const START_DAY: &str = "2020-07-04T00:00:00Z";
 
 pub const DEFAULT_BOUNDING_BOX: (Location, Location) =
-    (Location { lat: 52.4240, lng: 13.2148 }, Location { lat: 52.5937, lng: 13.5970 });
+    (Location::Coordinate { lat: 52.4240, lng: 13.2148 }, Location::Coordinate { lat: 52.5937, lng: 13.5970 });
 
 pub fn default_time_plus_offset(offset: i32) -> String {
     format_time(parse_time(&START_DAY.to_string()) + from_hours(offset).as_secs_f64())
@@ -64,8 +64,8 @@ pub fn default_job_prototype() -> impl Strategy<Value = Job> {
 
 pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
     from_costs(vec![
-        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003 },
-        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005 },
+        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003, waiting: None, service: None },
+        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005, waiting: None, service: None },
     ])
 }
 
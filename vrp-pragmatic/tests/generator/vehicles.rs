@@ -33,6 +33,9 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            parking: None,
+            service_factor: None,
+            speed_factor: None,
         }
     }
 }
@@ -164,7 +164,7 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None, service: None }
 }
 
 pub fn create_default_vehicle_type() -> VehicleType {
@@ -185,6 +185,9 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        parking: None,
+        service_factor: None,
+        speed_factor: None,
     }
 }
 
@@ -220,9 +223,12 @@ pub fn create_matrix_from_problem(problem: &Problem) -> Matrix {
 
     let data: Vec<i64> = unique
         .iter()
-        .cloned()
-        .flat_map(|a| {
-            unique.iter().map(move |b| ((a.lat - b.lat).powf(2.) + (a.lng - b.lng).powf(2.)).sqrt().round() as i64)
+        .map(|location| location.to_lat_lng())
+        .flat_map(|(a_lat, a_lng)| {
+            unique
+                .iter()
+                .map(|location| location.to_lat_lng())
+                .map(move |(b_lat, b_lng)| ((a_lat - b_lat).powf(2.) + (a_lng - b_lng).powf(2.)).sqrt().round() as i64)
         })
         .collect();
 
@@ -1,4 +1,4 @@
-use crate::format::solution::{Activity, Schedule, Solution, Stop};
+use crate::format::solution::{Activity, Schedule, Solution, Statistic, Stop, Tour};
 use crate::helpers::ToLocation;
 use std::cmp::Ordering::Less;
 use std::collections::HashMap;
@@ -37,6 +37,21 @@ pub fn create_stop_with_activity_with_tag(
     create_stop_with_activity_impl(id, activity_type, location, vec![load], time, distance, Some(job_tag.to_string()))
 }
 
+pub fn create_solution_with_stops(vehicle_id: &str, type_id: &str, stops: Vec<Stop>) -> Solution {
+    Solution {
+        statistic: Statistic::default(),
+        tours: vec![Tour {
+            vehicle_id: vehicle_id.to_string(),
+            type_id: type_id.to_string(),
+            shift_index: 0,
+            stops,
+            statistic: Statistic::default(),
+        }],
+        unassigned: vec![],
+        extras: None,
+    }
+}
+
 fn create_stop_with_activity_impl(
     id: &str,
     activity_type: &str,
@@ -58,6 +73,8 @@ fn create_stop_with_activity_impl(
             time: None,
             job_tag,
         }],
+        latest_arrival: None,
+        slack: None,
     }
 }
 
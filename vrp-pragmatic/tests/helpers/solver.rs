@@ -12,6 +12,12 @@ use vrp_core::utils::DefaultRandom;
 
 /// Runs solver with cheapest insertion heuristic.
 pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    remove_details(solve_with_cheapest_insertion_and_details(problem, matrices))
+}
+
+/// Runs solver with cheapest insertion heuristic and keeps latest arrival and slack of stops and
+/// objective values.
+pub fn solve_with_cheapest_insertion_and_details(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
     let problem_copy = problem.clone();
     let matrices_copy = matrices.clone();
 
@@ -62,7 +68,7 @@ pub fn solve_with_metaheuristic_and_iterations(
 
     assert_eq!(CheckerContext::new(problem_copy, matrices_copy, solution.clone()).check().err(), None);
 
-    solution
+    remove_details(solution)
 }
 
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
@@ -82,3 +88,18 @@ fn sort_all_data(solution: Solution) -> Solution {
 
     solution
 }
+
+/// Removes latest arrival and slack from stops and objective values from statistic as they are covered
+/// by dedicated tests and by checker.
+fn remove_details(solution: Solution) -> Solution {
+    let mut solution = solution;
+
+    solution.statistic.objectives = None;
+
+    solution.tours.iter_mut().flat_map(|tour| tour.stops.iter_mut()).for_each(|stop| {
+        stop.latest_arrival = None;
+        stop.slack = None;
+    });
+
+    solution
+}
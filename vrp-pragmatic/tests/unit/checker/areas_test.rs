@@ -0,0 +1,91 @@
+use super::*;
+use crate::format::problem::VehicleLimits;
+use crate::helpers::*;
+
+fn create_area(min: (f64, f64), max: (f64, f64)) -> Vec<Location> {
+    vec![
+        vec![min.0, min.1].to_loc(),
+        vec![max.0, min.1].to_loc(),
+        vec![max.0, max.1].to_loc(),
+        vec![min.0, max.1].to_loc(),
+    ]
+}
+
+parameterized_test! {can_check_areas, (areas, job_location, expected_result), {
+    can_check_areas_impl(areas, job_location, expected_result);
+}}
+
+can_check_areas! {
+    case01: (None, (1., 1.), Ok(())),
+    case02: (Some(vec![create_area((0., 0.), (2., 2.))]), (1., 1.), Ok(())),
+    case03: (Some(vec![create_area((5., 5.), (6., 6.)), create_area((0., 0.), (2., 2.))]), (1., 1.), Ok(())),
+
+    case04: (Some(vec![create_area((0., 0.), (2., 2.))]), (3., 1.), Err(())),
+    case05: (Some(vec![]), (1., 1.), Err(())),
+}
+
+fn can_check_areas_impl(areas: Option<Vec<Vec<Location>>>, job_location: (f64, f64), expected_result: Result<(), ()>) {
+    let result = check_areas(areas, job_location).map_err(|_| ());
+
+    assert_eq!(result, expected_result);
+}
+
+#[test]
+fn can_report_areas_with_index_locations_instead_of_panic() {
+    let area = vec![Location::new_reference(0), Location::new_reference(1), Location::new_reference(2)];
+
+    let result = check_areas(Some(vec![area]), (1., 1.));
+
+    assert!(result.unwrap_err().contains("areas can be used only with geocoordinates"));
+}
+
+fn check_areas(areas: Option<Vec<Vec<Location>>>, job_location: (f64, f64)) -> Result<(), String> {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![job_location.0, job_location.1])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits { max_distance: None, shift_time: None, allowed_areas: areas }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                job_location,
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                2,
+            ),
+        ],
+    );
+
+    let context = CheckerContext::new(problem, None, solution);
+
+    check_tour_areas(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message))
+}
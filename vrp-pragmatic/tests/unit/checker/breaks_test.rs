@@ -46,7 +46,10 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
             cost: 22.,
             distance: 4,
             duration: 8,
-            times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+            times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+            gap: None,
+            costs: None,
+            objectives: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -93,6 +96,8 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
                             job_tag: None,
                         },
                     ],
+                    latest_arrival: None,
+                    slack: None,
                 },
                 create_stop_with_activity(
                     "arrival",
@@ -107,14 +112,22 @@ fn can_check_breaks_impl(break_times: VehicleBreakTime, expected_result: Result<
                 cost: 22.,
                 distance: 4,
                 duration: 8,
-                times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+                times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: None,
+                objectives: None,
             },
         }],
         unassigned: vec![],
         extras: None,
     };
 
-    let result = check_breaks(&CheckerContext::new(problem, None, solution));
+    let context = CheckerContext::new(problem, None, solution);
+
+    let result = check_tour_breaks(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
 
     assert_eq!(result, expected_result);
 }
@@ -57,7 +57,10 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
             cost: 13.,
             distance: 1,
             duration: 2,
-            times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+            times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+            gap: None,
+            costs: None,
+            objectives: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -96,6 +99,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             job_tag: None,
                         },
                     ],
+                    latest_arrival: None,
+                    slack: None,
                 },
                 Stop {
                     location: vec![0., 0.].to_loc(),
@@ -112,6 +117,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         time: None,
                         job_tag: None,
                     }],
+                    latest_arrival: None,
+                    slack: None,
                 },
                 Stop {
                     location: vec![2., 0.].to_loc(),
@@ -143,6 +150,8 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             job_tag: None,
                         },
                     ],
+                    latest_arrival: None,
+                    slack: None,
                 },
                 create_stop_with_activity(
                     "job4",
@@ -173,14 +182,22 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 cost: 13.,
                 distance: 1,
                 duration: 2,
-                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: None,
+                objectives: None,
             },
         }],
         unassigned: vec![],
         extras: None,
     };
 
-    let result = check_vehicle_load(&CheckerContext::new(problem, None, solution));
+    let context = CheckerContext::new(problem, None, solution);
+
+    let result = check_tour_load(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
 
     assert_eq!(result, expected_result);
 }
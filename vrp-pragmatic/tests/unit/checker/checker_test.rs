@@ -0,0 +1,173 @@
+use super::*;
+use crate::helpers::*;
+use std::io::BufWriter;
+
+fn create_test_tour(vehicle_id: &str, job_id: &str) -> Tour {
+    create_solution_with_stops(
+        vehicle_id,
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                job_id,
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                2,
+            ),
+        ],
+    )
+    .tours
+    .remove(0)
+}
+
+#[test]
+fn can_create_report_with_all_violations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique".to_string()]),
+                create_delivery_job_with_skills("job2", vec![1., 0.], vec!["unique".to_string()]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![create_test_tour("my_vehicle_1", "job1"), create_test_tour("my_vehicle_2", "job2")],
+        ..create_solution_with_stops("my_vehicle_1", "my_vehicle", vec![])
+    };
+    let context = CheckerContext::new(problem, None, solution);
+
+    let report = context.create_report();
+
+    let violations = report.violations.iter().filter(|violation| violation.code == "C0006").collect::<Vec<_>>();
+    assert_eq!(violations.len(), 2);
+    violations.iter().zip(vec!["my_vehicle_1", "my_vehicle_2"]).for_each(|(violation, vehicle_id)| {
+        assert_eq!(violation.severity, Severity::Hard);
+        assert_eq!(violation.vehicle_id, Some(vehicle_id.to_string()));
+        assert_eq!(violation.shift_index, Some(0));
+        assert_eq!(violation.stop_index, Some(1));
+        assert_eq!(violation.activity_index, Some(0));
+    });
+    assert!(!report.is_feasible());
+    assert!(context.check().is_err());
+}
+
+#[test]
+fn can_serialize_report() {
+    let report = CheckerReport {
+        violations: vec![Violation {
+            code: "C0003".to_string(),
+            severity: Severity::Soft,
+            vehicle_id: Some("my_vehicle_1".to_string()),
+            shift_index: Some(0),
+            stop_index: None,
+            activity_index: None,
+            message: "message".to_string(),
+        }],
+    };
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_report(writer, &report).unwrap();
+
+    assert_eq!(
+        buffer.split_whitespace().collect::<String>(),
+        r#"{"violations":[{"code":"C0003","severity":"soft","vehicleId":"my_vehicle_1","shiftIndex":0,"message":"message"}]}"#
+    );
+    assert!(report.is_feasible());
+}
+
+#[test]
+fn can_create_report_with_violations_of_the_same_rule_at_different_stops() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(100, 200)], 1.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(100, 200)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                2,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                1,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "delivery",
+                (2., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                2,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
+                4,
+            ),
+        ],
+    );
+    let context = CheckerContext::new(problem, None, solution);
+
+    let report = context.create_report();
+
+    let violations = report
+        .violations
+        .iter()
+        .filter(|violation| violation.code == "C0005" && violation.message.contains("violates time window"))
+        .map(|violation| (violation.vehicle_id.clone(), violation.stop_index, violation.activity_index))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        vec![
+            (Some("my_vehicle_1".to_string()), Some(1), Some(0)),
+            (Some("my_vehicle_1".to_string()), Some(2), Some(0))
+        ]
+    );
+}
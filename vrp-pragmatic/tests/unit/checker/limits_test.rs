@@ -0,0 +1,69 @@
+use super::*;
+use crate::format::problem::VehicleLimits;
+use crate::helpers::*;
+
+parameterized_test! {can_check_limits, (max_distance, shift_time, expected_result), {
+    can_check_limits_impl(max_distance, shift_time, expected_result);
+}}
+
+can_check_limits! {
+    case01: (None, None, Ok(())),
+    case02: (Some(10.), Some(10.), Ok(())),
+    case03: (Some(2.), Some(4.), Ok(())),
+
+    case04: (Some(1.), None, Err("Tour 'my_vehicle_1' exceeds max distance limit: 2 > 1".to_string())),
+    case05: (None, Some(2.), Err("Tour 'my_vehicle_1' exceeds shift time limit: 4 > 2".to_string())),
+}
+
+fn can_check_limits_impl(max_distance: Option<f64>, shift_time: Option<f64>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits { max_distance, shift_time, allowed_areas: None }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:01Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:05Z", "1970-01-01T00:00:05Z"),
+                2,
+            ),
+        ],
+    );
+
+    let context = CheckerContext::new(problem, None, solution);
+
+    let result = check_tour_limits(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_priorities, (priorities, expected_result), {
+    can_check_priorities_impl(priorities, expected_result);
+}}
+
+can_check_priorities! {
+    case01: (vec![None, None, None], Ok(())),
+    case02: (vec![Some(1), Some(2), Some(2)], Ok(())),
+    case03: (vec![None, Some(2), Some(3)], Ok(())),
+
+    case04: (vec![Some(2), Some(1), None], Err("Job 'job2' with priority 1 is served after job 'job1' with priority 2 in tour 'my_vehicle_1'".to_string())),
+    case05: (vec![None, Some(3), Some(2)], Err("Job 'job3' with priority 2 is served after job 'job2' with priority 3 in tour 'my_vehicle_1'".to_string())),
+}
+
+fn can_check_priorities_impl(priorities: Vec<Option<i32>>, expected_result: Result<(), String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: priorities
+                .iter()
+                .enumerate()
+                .map(|(idx, priority)| Job {
+                    priority: *priority,
+                    ..create_delivery_job(format!("job{}", idx + 1).as_str(), vec![(idx + 1) as f64, 0.])
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                3,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                2,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "delivery",
+                (2., 0.),
+                1,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                2,
+            ),
+            create_stop_with_activity(
+                "job3",
+                "delivery",
+                (3., 0.),
+                0,
+                ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                3,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:09Z", "1970-01-01T00:00:09Z"),
+                6,
+            ),
+        ],
+    );
+
+    let context = CheckerContext::new(problem, None, solution);
+
+    let result = check_tour_priorities(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
+
+    assert_eq!(result, expected_result);
+}
@@ -105,6 +105,9 @@ mod single {
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    parking: None,
+                    service_factor: None,
+                    speed_factor: None,
                 }],
                 profiles: create_default_profiles(),
             },
@@ -115,7 +118,10 @@ mod single {
                 cost: 51.,
                 distance: 16,
                 duration: 25,
-                times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2, parking: 0 },
+                gap: None,
+                costs: None,
+                objectives: None,
             },
             tours: vec![
                 VehicleTour {
@@ -163,6 +169,8 @@ mod single {
                                     job_tag: None,
                                 },
                             ],
+                            latest_arrival: None,
+                            slack: None,
                         },
                         create_stop_with_activity(
                             "job3",
@@ -209,7 +217,10 @@ mod single {
                         cost: 51.,
                         distance: 16,
                         duration: 25,
-                        times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                        times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2, parking: 0 },
+                        gap: None,
+                        costs: None,
+                        objectives: None,
                     },
                 },
                 VehicleTour {
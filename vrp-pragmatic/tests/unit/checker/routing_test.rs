@@ -0,0 +1,113 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_routing, (job_time, job_distance, total_cost, expected_result), {
+    can_check_routing_impl(job_time, job_distance, total_cost, expected_result);
+}}
+
+can_check_routing! {
+    case01: ((5., 6.), 5, 31., Ok(())),
+
+    case02: ((3., 4.), 5, 31., Err("Schedule mismatch at stop 1 in tour 'my_vehicle_1': expected arrival time 1970-01-01T00:00:05Z, got 1970-01-01T00:00:03Z".to_string())),
+    case03: ((5., 8.), 5, 31., Err("Activity 'delivery' of 'job1' in tour 'my_vehicle_1' violates time window or duration".to_string())),
+    case04: ((5., 6.), 4, 31., Err("Distance mismatch at stop 1 in tour 'my_vehicle_1': expected 5, got 4".to_string())),
+    case05: ((5., 6.), 5, 35., Err("Statistic mismatch for tour 'my_vehicle_1': expected cost 31, got 35".to_string())),
+}
+
+fn can_check_routing_impl(
+    job_time: (f64, f64),
+    job_distance: i32,
+    total_cost: f64,
+    expected_result: Result<(), String>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let statistic = Statistic {
+        cost: total_cost,
+        distance: 10,
+        duration: 11,
+        times: Timing { driving: 10, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+        gap: None,
+        costs: None,
+        objectives: None,
+    };
+    let solution = Solution {
+        statistic: statistic.clone(),
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (5., 0.),
+                    0,
+                    (format_time(job_time.0).as_str(), format_time(job_time.1).as_str()),
+                    job_distance,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                    10,
+                ),
+            ],
+            statistic,
+        }],
+        unassigned: vec![],
+        extras: None,
+    };
+
+    let context = CheckerContext::new(problem, Some(vec![matrix]), solution);
+
+    let result = check_tour_routing(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_cost_breakdown, (fixed, cost_objective, expected_result), {
+    can_check_cost_breakdown_impl(fixed, cost_objective, expected_result);
+}}
+
+can_check_cost_breakdown! {
+    case01: (10., 31., Ok(())),
+    case02: (14., 31., Err("Statistic mismatch for solution: expected cost breakdown 31, got 35".to_string())),
+    case03: (10., 35., Err("Statistic mismatch for solution: expected cost objective 31, got 35".to_string())),
+}
+
+fn can_check_cost_breakdown_impl(fixed: f64, cost_objective: f64, expected_result: Result<(), String>) {
+    let statistic = Statistic {
+        cost: 31.,
+        distance: 10,
+        duration: 11,
+        times: Timing { driving: 10, serving: 1, waiting: 0, break_time: 0, parking: 0 },
+        gap: None,
+        costs: Some(CostStatistic { fixed, distance: 10., driving: 10., waiting: 0., serving: 1. }),
+        objectives: Some(ObjectiveStatistic {
+            primary: vec![ObjectiveValue { name: "minimize-unassigned".to_string(), value: 0. }],
+            secondary: vec![ObjectiveValue { name: "minimize-cost".to_string(), value: cost_objective }],
+        }),
+    };
+
+    let result = check_statistic("solution", &statistic, &statistic, 0);
+
+    assert_eq!(result, expected_result);
+}
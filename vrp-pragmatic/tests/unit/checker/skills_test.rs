@@ -0,0 +1,73 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_check_skills, (vehicle_skills, job_skills, expected_result), {
+    can_check_skills_impl(vehicle_skills, job_skills, expected_result);
+}}
+
+can_check_skills! {
+    case01: (None, None, Ok(())),
+    case02: (Some(vec!["unique1", "unique2"]), Some(vec!["unique1"]), Ok(())),
+    case03: (Some(vec!["unique1"]), None, Ok(())),
+
+    case04: (None, Some(vec!["unique1"]), Err(())),
+    case05: (Some(vec!["unique2"]), Some(vec!["unique1"]), Err(())),
+    case06: (Some(vec!["unique1"]), Some(vec!["unique1", "unique2"]), Err(())),
+}
+
+fn can_check_skills_impl(
+    vehicle_skills: Option<Vec<&str>>,
+    job_skills: Option<Vec<&str>>,
+    expected_result: Result<(), ()>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: job_skills.map(to_strings), ..create_delivery_job("job1", vec![1., 0.]) }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { skills: vehicle_skills.map(to_strings), ..create_default_vehicle_type() }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                2,
+            ),
+        ],
+    );
+
+    let context = CheckerContext::new(problem, None, solution);
+
+    let result = check_tour_skills(&context, &context.solution.tours[0])
+        .into_iter()
+        .next()
+        .map_or(Ok(()), |violation| Err(violation.message));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
@@ -46,8 +46,8 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
         unassigned: Default::default(),
         locked: Default::default(),
         state: Default::default(),
-        routes: vec![RouteContext {
-            route: Arc::new(create_route_with_activities(
+        routes: vec![RouteContext::new_with_state(
+            Arc::new(create_route_with_activities(
                 &fleet,
                 "v1",
                 vec![
@@ -56,8 +56,8 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
                     create_activity_with_job_at_location(create_single("job2"), 3),
                 ],
             )),
-            state: Arc::new(RouteState::default()),
-        }],
+            Arc::new(RouteState::default()),
+        )],
         registry: Registry::new(&fleet),
     };
 
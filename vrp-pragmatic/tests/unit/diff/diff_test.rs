@@ -0,0 +1,127 @@
+use super::*;
+use crate::format::solution::{Stop, UnassignedJob, UnassignedJobReason};
+use crate::helpers::*;
+use std::io::BufWriter;
+
+fn create_job_stop(job_id: &str, arrival: &str) -> Stop {
+    create_stop_with_activity(job_id, "delivery", (1., 0.), 0, (arrival, arrival), 1)
+}
+
+fn create_tour_solution(vehicle_id: &str, jobs: Vec<(&str, &str)>) -> Solution {
+    create_solution_with_stops(
+        vehicle_id,
+        "my_vehicle",
+        std::iter::once(create_stop_with_activity(
+            "departure",
+            "departure",
+            (0., 0.),
+            1,
+            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+            0,
+        ))
+        .chain(jobs.into_iter().map(|(job_id, arrival)| create_job_stop(job_id, arrival)))
+        .collect(),
+    )
+}
+
+fn create_unassigned_job(job_id: &str) -> UnassignedJob {
+    UnassignedJob {
+        job_id: job_id.to_string(),
+        reasons: vec![UnassignedJobReason { code: 0, description: "unknown".to_string(), vehicle_id: None }],
+    }
+}
+
+#[test]
+fn can_detect_no_changes() {
+    let solution = create_tour_solution("v1", vec![("job1", "1970-01-01T00:00:10Z"), ("job2", "1970-01-01T00:00:20Z")]);
+
+    let diff = diff_solutions(&solution, &solution, 0);
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.statistic, StatisticDelta::default());
+}
+
+#[test]
+fn can_detect_order_changes_and_arrival_shifts() {
+    let old = create_tour_solution("v1", vec![("job1", "1970-01-01T00:00:10Z"), ("job2", "1970-01-01T00:00:20Z")]);
+    let new = create_tour_solution("v1", vec![("job2", "1970-01-01T00:00:12Z"), ("job1", "1970-01-01T00:05:00Z")]);
+
+    let diff = diff_solutions(&old, &new, 10);
+
+    assert_eq!(
+        diff.order_changes,
+        vec![TourOrderChange {
+            vehicle_id: "v1".to_string(),
+            shift_index: 0,
+            old_order: vec!["job1".to_string(), "job2".to_string()],
+            new_order: vec!["job2".to_string(), "job1".to_string()],
+        }]
+    );
+    assert_eq!(
+        diff.arrival_shifts,
+        vec![ArrivalShift {
+            job_id: "job1".to_string(),
+            activity_type: "delivery".to_string(),
+            vehicle_id: "v1".to_string(),
+            old_arrival: "1970-01-01T00:00:10Z".to_string(),
+            new_arrival: "1970-01-01T00:05:00Z".to_string(),
+            shift: 290,
+        }]
+    );
+    assert!(diff.moved_jobs.is_empty());
+}
+
+#[test]
+fn can_detect_moved_jobs_and_assignment_changes() {
+    let mut old = create_tour_solution("v1", vec![("job1", "1970-01-01T00:00:10Z"), ("job2", "1970-01-01T00:00:20Z")]);
+    old.unassigned = vec![create_unassigned_job("job3")];
+    let mut new = create_tour_solution("v2", vec![("job1", "1970-01-01T00:00:10Z"), ("job3", "1970-01-01T00:00:20Z")]);
+    new.unassigned = vec![create_unassigned_job("job2")];
+    new.statistic.cost = 10.;
+    new.statistic.distance = 5;
+
+    let diff = diff_solutions(&old, &new, 0);
+
+    assert_eq!(
+        diff.moved_jobs,
+        vec![MovedJob {
+            job_id: "job1".to_string(),
+            from_vehicle_id: "v1".to_string(),
+            to_vehicle_id: "v2".to_string()
+        }]
+    );
+    assert!(diff.order_changes.is_empty());
+    assert_eq!(diff.newly_unassigned, vec!["job2".to_string()]);
+    assert_eq!(diff.newly_assigned, vec!["job3".to_string()]);
+    assert_eq!(diff.statistic.cost, 10.);
+    assert_eq!(diff.statistic.distance, 5);
+    assert_eq!(diff.statistic.tours, 0);
+}
+
+#[test]
+fn can_serialize_diff_as_text() {
+    let diff = SolutionDiff {
+        moved_jobs: vec![MovedJob {
+            job_id: "job1".to_string(),
+            from_vehicle_id: "v1".to_string(),
+            to_vehicle_id: "v2".to_string(),
+        }],
+        newly_unassigned: vec!["job2".to_string()],
+        statistic: StatisticDelta { cost: -1.5, tours: 1, ..StatisticDelta::default() },
+        ..SolutionDiff::default()
+    };
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_diff_as_text(writer, &diff).unwrap();
+
+    assert_eq!(
+        buffer.lines().collect::<Vec<_>>(),
+        vec![
+            "statistic: cost -1.50, distance +0, duration +0, tours +1",
+            "times: driving +0, serving +0, waiting +0, break +0",
+            "moved: job 'job1' from 'v1' to 'v2'",
+            "unassigned: job 'job2'",
+        ]
+    );
+}
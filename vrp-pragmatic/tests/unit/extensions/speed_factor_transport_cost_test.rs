@@ -0,0 +1,29 @@
+use super::*;
+use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
+
+fn create_transport_cost() -> SpeedFactorTransportCost {
+    let matrices = vec![
+        MatrixData::new(0, vec![0., 10., -1., 0.], vec![0., 100., -1., 0.]),
+        MatrixData::new(1, vec![0., 20., 20., 0.], vec![0., 200., 200., 0.]),
+    ];
+    let inner = create_matrix_transport_cost(matrices).unwrap();
+
+    SpeedFactorTransportCost::new(inner, 2, vec![(0, 2.), (1, 0.5)])
+}
+
+parameterized_test! {can_apply_speed_factor_to_profile, (profile, from, to, expected), {
+    let transport = create_transport_cost();
+
+    assert_eq!(
+        (transport.duration(profile, from, to, 0.), transport.distance(profile, from, to, 0.)),
+        expected
+    );
+}}
+
+can_apply_speed_factor_to_profile! {
+    case01: (0, 0, 1, (10., 100.)),
+    case02: (1, 0, 1, (20., 200.)),
+    case03: (2, 0, 1, (5., 100.)),
+    case04: (3, 0, 1, (40., 200.)),
+    case05: (2, 1, 0, (-1., -1.)),
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+fn create_binary_matrix(profile: &str, size: u32, values: &[u32], error_codes: Option<&[u32]>) -> Vec<u8> {
+    let mut buffer = BINARY_MAGIC.to_vec();
+    let mut write_u32 = |buffer: &mut Vec<u8>, value: u32| buffer.extend_from_slice(&value.to_le_bytes());
+
+    write_u32(&mut buffer, BINARY_VERSION);
+    write_u32(&mut buffer, profile.len() as u32);
+    buffer.extend_from_slice(profile.as_bytes());
+    write_u32(&mut buffer, 0);
+    write_u32(&mut buffer, size);
+    write_u32(&mut buffer, if error_codes.is_some() { BINARY_HAS_ERROR_CODES } else { 0 });
+
+    values.iter().chain(values.iter()).chain(error_codes.unwrap_or(&[]).iter()).for_each(|value| {
+        write_u32(&mut buffer, *value);
+    });
+
+    buffer
+}
+
+fn deserialize(data: &[u8], format: MatrixFormat) -> Result<Matrix, Vec<FormatError>> {
+    deserialize_matrix_with_format(BufReader::new(data), format)
+}
+
+parameterized_test! {can_get_matrix_format_from_path, (path, expected), {
+    assert_eq!(MatrixFormat::from_path(path), expected);
+}}
+
+can_get_matrix_format_from_path! {
+    case01: ("matrix.json", MatrixFormat::Json),
+    case02: ("data/matrix.bin", MatrixFormat::Binary),
+    case03: ("matrix.CSV", MatrixFormat::Csv),
+    case04: ("matrix", MatrixFormat::Json),
+    case05: ("dir.bin/matrix", MatrixFormat::Json),
+}
+
+#[test]
+fn can_read_binary_matrix() {
+    let data = create_binary_matrix("car", 2, &[0, 3, 4, 0], Some(&[0, 1, 0, 0]));
+
+    let matrix = deserialize(data.as_slice(), MatrixFormat::Binary).ok().unwrap();
+
+    assert_eq!(matrix.profile, "car");
+    assert_eq!(matrix.timestamp, None);
+    assert_eq!(matrix.travel_times, vec![0, 3, 4, 0]);
+    assert_eq!(matrix.distances, vec![0, 3, 4, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 1, 0, 0]));
+}
+
+#[test]
+fn can_detect_truncated_binary_matrix() {
+    let data = create_binary_matrix("car", 2, &[0, 3, 4, 0], None);
+
+    let result = deserialize(&data[..data.len() - 1], MatrixFormat::Binary);
+
+    assert_eq!(result.err().map(|errors| errors[0].code.clone()), Some("E0001".to_string()));
+}
+
+parameterized_test! {can_detect_binary_matrix_with_invalid_header, (profile_length, size), {
+    can_detect_binary_matrix_with_invalid_header_impl(profile_length, size);
+}}
+
+can_detect_binary_matrix_with_invalid_header! {
+    case01: (3, 0x4000_0000),
+    case02: (u32::MAX, 2),
+}
+
+fn can_detect_binary_matrix_with_invalid_header_impl(profile_length: u32, size: u32) {
+    let mut data = create_binary_matrix("car", size, &[0, 3, 4, 0], None);
+    data[8..12].copy_from_slice(&profile_length.to_le_bytes());
+
+    let result = deserialize(data.as_slice(), MatrixFormat::Binary);
+
+    assert_eq!(result.err().map(|errors| errors[0].code.clone()), Some("E0001".to_string()));
+}
+
+#[test]
+fn can_read_csv_matrix_with_error_codes() {
+    let data = "car,0,1,3,30\ncar,0,2,5,50\ncar,1,0,4,40\ncar,1,2,2,20,1\ncar,2,0,6,60\ncar,2,1,1,10\n";
+
+    let matrix = deserialize(data.as_bytes(), MatrixFormat::Csv).ok().unwrap();
+
+    assert_eq!(matrix.travel_times, vec![0, 3, 5, 4, 0, 2, 6, 1, 0]);
+    assert_eq!(matrix.distances, vec![0, 30, 50, 40, 0, 20, 60, 10, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 0, 0, 0, 0, 1, 0, 0, 0]));
+}
+
+#[test]
+fn can_read_csv_matrix() {
+    let data = "profile,from,to,duration,distance\ncar,0,1,3,30\ncar,1,0,4,40\n";
+
+    let matrix = deserialize(data.as_bytes(), MatrixFormat::Csv).ok().unwrap();
+
+    assert_eq!(matrix.profile, "car");
+    assert_eq!(matrix.travel_times, vec![0, 3, 4, 0]);
+    assert_eq!(matrix.distances, vec![0, 30, 40, 0]);
+    assert_eq!(matrix.error_codes, None);
+}
+
+parameterized_test! {can_detect_invalid_csv_matrix, data, {
+    let result = deserialize(data.as_bytes(), MatrixFormat::Csv);
+
+    assert_eq!(result.err().map(|errors| errors[0].code.clone()), Some("E0001".to_string()));
+}}
+
+can_detect_invalid_csv_matrix! {
+    case01: "",
+    case02: "car,0,1,3,30\n",
+    case03: "car,0,1,3,30\ntruck,1,0,4,40\n",
+    case04: "car,0,1,3\ncar,1,0,4,40\n",
+    case05: "car,0,1,a,30\ncar,1,0,4,40\n",
+    case06: "car,1,0,4,40\ncar,0,1,3,30\n",
+    case07: "car,0,1,3,30\ncar,1,2,4,40\n",
+    case08: "car,4294967296,0,1,1\n",
+    case09: "car,0,1,3,30\ncar,1,0,4,40\ncar,1,0,4,40\n",
+}
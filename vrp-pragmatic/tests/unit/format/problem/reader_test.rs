@@ -138,7 +138,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: "car".to_string(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., waiting: None, service: None },
                 shifts: vec![VehicleShift {
                     start: VehiclePlace {
                         time: "1970-01-01T00:00:00Z".to_string(),
@@ -161,6 +161,9 @@ fn can_read_complex_problem() {
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
                 limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.), allowed_areas: None }),
+                parking: None,
+                service_factor: None,
+                speed_factor: None,
             }],
             profiles: create_default_profiles(),
         },
@@ -170,8 +173,8 @@ fn can_read_complex_problem() {
     let matrix = Matrix {
         profile: "car".to_owned(),
         timestamp: None,
-        travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
-        distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+        travel_times: vec![0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0],
+        distances: vec![0, 2, 2, 2, 2, 2, 0, 2, 2, 2, 2, 2, 0, 2, 2, 2, 2, 2, 0, 2, 2, 2, 2, 2, 0],
         error_codes: Option::None,
     };
 
@@ -0,0 +1,42 @@
+use super::*;
+use crate::helpers::*;
+
+#[test]
+fn can_serialize_solution_as_csv() {
+    let solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity_md(
+                "job,1",
+                "delivery",
+                (1., 0.),
+                vec![0, 2],
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+        ],
+    );
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_solution_as_csv(writer, &solution).unwrap();
+
+    assert_eq!(
+        buffer.lines().collect::<Vec<_>>(),
+        vec![
+            "vehicle_id,type_id,shift_index,stop_index,activity_index,job_id,activity_type,job_tag,lat,lng,location_index,\
+             arrival,departure,load,distance",
+            "my_vehicle_1,my_vehicle,0,0,0,departure,departure,,0,0,,1970-01-01T00:00:00Z,1970-01-01T00:00:00Z,1,0",
+            "my_vehicle_1,my_vehicle,0,1,0,\"job,1\",delivery,,1,0,,1970-01-01T00:00:01Z,1970-01-01T00:00:02Z,0 2,1",
+        ]
+    );
+}
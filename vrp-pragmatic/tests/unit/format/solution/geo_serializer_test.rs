@@ -0,0 +1,141 @@
+use super::*;
+use crate::format::solution::UnassignedJobReason;
+use crate::helpers::*;
+use serde_json::Value;
+
+fn create_test_solution() -> Solution {
+    let mut solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (1., 0.),
+                0,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "my_vehicle_1_break",
+                "break",
+                (2., 0.),
+                0,
+                ("1970-01-01T00:00:03Z", "1970-01-01T00:00:05Z"),
+                2,
+            ),
+            create_stop_with_activity(
+                "reload",
+                "reload",
+                (0., 0.),
+                1,
+                ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
+                4,
+            ),
+        ],
+    );
+    solution.unassigned = vec![UnassignedJob {
+        job_id: "job2".to_string(),
+        reasons: vec![UnassignedJobReason {
+            code: 1,
+            description: "cannot serve required skill".to_string(),
+            vehicle_id: Some("my_vehicle_1".to_string()),
+        }],
+    }];
+
+    solution
+}
+
+fn serialize_with_options(solution: &Solution, options: &GeoJsonOptions) -> Value {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_solution_as_geojson_with_options(writer, solution, options).unwrap();
+
+    serde_json::from_str(buffer.as_str()).unwrap()
+}
+
+fn get_property<'a>(feature: &'a Value, name: &str) -> &'a str {
+    feature["properties"][name].as_str().unwrap()
+}
+
+fn create_unassigned_options(per_tour: bool) -> GeoJsonOptions {
+    GeoJsonOptions {
+        unassigned_locations: vec![("job2".to_string(), vec![Location::Coordinate { lat: 3., lng: 1. }])]
+            .into_iter()
+            .collect(),
+        per_tour,
+    }
+}
+
+#[test]
+fn can_distinguish_stop_types() {
+    let geojson = serialize_with_options(&create_test_solution(), &GeoJsonOptions::default());
+
+    let features = geojson["features"].as_array().unwrap();
+    let stop_types = features
+        .iter()
+        .filter(|feature| feature["geometry"]["type"] == "Point")
+        .map(|feature| (get_property(feature, "stop_type"), get_property(feature, "marker-symbol")))
+        .collect::<Vec<_>>();
+
+    assert_eq!(features.len(), 5);
+    assert_eq!(stop_types, vec![("depot", "warehouse"), ("job", "marker"), ("break", "beer"), ("reload", "industry")]);
+}
+
+#[test]
+fn can_add_tour_statistic_to_line() {
+    let geojson = serialize_with_options(&create_test_solution(), &GeoJsonOptions::default());
+
+    let line = geojson["features"].as_array().unwrap().last().unwrap();
+
+    assert_eq!(line["geometry"]["type"], "LineString");
+    assert_eq!(get_property(line, "vehicle_id"), "my_vehicle_1");
+    assert_eq!(get_property(line, "type_id"), "my_vehicle");
+    assert_eq!(get_property(line, "stops"), "4");
+    assert_eq!(get_property(line, "distance"), "4");
+    assert_eq!(get_property(line, "load_profile"), "1,0,0,1");
+}
+
+#[test]
+fn can_serialize_unassigned_jobs_with_locations() {
+    let geojson = serialize_with_options(&create_test_solution(), &create_unassigned_options(false));
+
+    let unassigned = geojson["features"].as_array().unwrap().last().unwrap();
+
+    assert_eq!(unassigned["geometry"]["coordinates"], serde_json::json!([1., 3.]));
+    assert_eq!(get_property(unassigned, "job_id"), "job2");
+    assert_eq!(get_property(unassigned, "marker-symbol"), "cross");
+    assert_eq!(get_property(unassigned, "reasons"), "1: cannot serve required skill (my_vehicle_1)");
+}
+
+#[test]
+fn can_serialize_feature_collection_per_tour() {
+    let geojson = serialize_with_options(&create_test_solution(), &create_unassigned_options(true));
+
+    let collections = geojson.as_array().unwrap();
+
+    assert_eq!(collections.len(), 2);
+    assert_eq!(collections[0]["type"], "FeatureCollection");
+    assert_eq!(collections[0]["features"].as_array().unwrap().len(), 5);
+    assert_eq!(collections[1]["features"].as_array().unwrap().len(), 1);
+    assert_eq!(get_property(&collections[1]["features"][0], "job_id"), "job2");
+}
+
+#[test]
+fn can_skip_locations_represented_by_index() {
+    let mut solution = create_test_solution();
+    solution.tours[0].stops.iter_mut().for_each(|stop| stop.location = Location::new_reference(0));
+
+    let geojson = serialize_with_options(&solution, &GeoJsonOptions::default());
+
+    assert!(geojson["features"].as_array().unwrap().is_empty());
+}
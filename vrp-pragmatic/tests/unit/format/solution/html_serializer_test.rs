@@ -0,0 +1,80 @@
+use super::*;
+use crate::format::solution::{UnassignedJob, UnassignedJobReason};
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_test_solution() -> Solution {
+    Solution {
+        unassigned: vec![UnassignedJob {
+            job_id: "job<2>".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 1,
+                description: "cannot serve required skill".to_string(),
+                vehicle_id: Some("my_vehicle_1".to_string()),
+            }],
+        }],
+        ..create_solution_with_stops(
+            "my_vehicle_1",
+            "my_vehicle",
+            vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                    2,
+                ),
+            ],
+        )
+    }
+}
+
+#[test]
+fn can_serialize_solution_as_html() {
+    let solution = create_test_solution();
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_solution_as_html(writer, &solution).unwrap();
+
+    assert!(buffer.starts_with("<!DOCTYPE html>"));
+    assert!(buffer.contains("<script type=\"application/json\" id=\"geojson\">"));
+    assert!(buffer.contains("\"vehicle_id\": \"my_vehicle_1\""));
+    assert!(buffer.contains("<div class=\"job\" style=\"left: 33.333%; width: 33.333%\" title=\"job1: "));
+    assert!(buffer.contains("<h2>Unassigned jobs (1)</h2>"));
+    assert!(buffer.contains("<td>job&lt;2&gt;</td><td>1</td><td>cannot serve required skill</td><td>my_vehicle_1</td>"));
+    assert!(buffer.trim_end().ends_with("</html>"));
+}
+
+#[test]
+fn can_serialize_unassigned_jobs_on_map_using_options() {
+    let solution = create_test_solution();
+    let options = GeoJsonOptions {
+        unassigned_locations: vec![("job<2>".to_string(), vec![Location::new(1., 2.)])].into_iter().collect(),
+        per_tour: true,
+    };
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_solution_as_html_with_options(writer, &solution, &options).unwrap();
+
+    assert!(buffer.contains("<script type=\"application/json\" id=\"geojson\">{"));
+    assert!(buffer.contains("\"job_id\": \"job<2>\""));
+}
@@ -0,0 +1,230 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::LockOrder;
+
+fn create_test_solution() -> Solution {
+    let mut solution = create_solution_with_stops(
+        "my_vehicle_1",
+        "my_vehicle",
+        vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "pickup",
+                (1., 0.),
+                1,
+                ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "break",
+                "break",
+                (1., 0.),
+                1,
+                ("1970-01-01T00:00:02Z", "1970-01-01T00:00:04Z"),
+                1,
+            ),
+            create_stop_with_activity(
+                "job2",
+                "delivery",
+                (2., 0.),
+                1,
+                ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                2,
+            ),
+            create_stop_with_activity(
+                "job1",
+                "delivery",
+                (3., 0.),
+                0,
+                ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
+                3,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                6,
+            ),
+        ],
+    );
+
+    let mut second_tour = solution.tours.first().unwrap().clone();
+    second_tour.vehicle_id = "my_vehicle_2".to_string();
+    second_tour.stops.truncate(1);
+    solution.tours.push(second_tour);
+
+    solution
+}
+
+fn create_relation(vehicle_id: &str, shift_index: Option<usize>, jobs: Vec<&str>) -> Relation {
+    Relation { type_field: RelationType::Any, jobs: to_strings(jobs), vehicle_id: vehicle_id.to_string(), shift_index }
+}
+
+#[test]
+fn can_create_relations_from_tours() {
+    let relations = create_relations(&create_test_solution(), RelationType::Strict, None);
+
+    assert_eq!(relations.len(), 2);
+    assert_eq!(relations[0].type_field, RelationType::Sequence);
+    assert_eq!(relations[0].vehicle_id, "my_vehicle_1");
+    assert_eq!(relations[0].shift_index, Some(0));
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job1", "job2", "arrival"]));
+    assert_eq!(relations[1].type_field, RelationType::Strict);
+    assert_eq!(relations[1].jobs, to_strings(vec!["departure"]));
+}
+
+#[test]
+fn can_create_relations_for_specific_vehicles() {
+    let vehicle_ids = to_strings(vec!["my_vehicle_2"]);
+
+    let relations = create_relations(&create_test_solution(), RelationType::Sequence, Some(vehicle_ids.as_slice()));
+
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].type_field, RelationType::Sequence);
+    assert_eq!(relations[0].vehicle_id, "my_vehicle_2");
+}
+
+#[test]
+fn can_merge_relations_replacing_ones_of_the_same_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![],
+            relations: Some(vec![
+                create_relation("my_vehicle_1", None, vec!["job3"]),
+                create_relation("my_vehicle_1", Some(1), vec!["job4"]),
+                create_relation("my_vehicle_2", None, vec!["job5"]),
+            ]),
+        },
+        ..create_empty_problem()
+    };
+    let vehicle_ids = to_strings(vec!["my_vehicle_1"]);
+    let relations = create_relations(&create_test_solution(), RelationType::Strict, Some(vehicle_ids.as_slice()));
+
+    let problem = merge_relations(problem, relations);
+
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(
+        relations.iter().map(|relation| (relation.vehicle_id.as_str(), relation.jobs.len())).collect::<Vec<_>>(),
+        vec![("my_vehicle_1", 1), ("my_vehicle_2", 1), ("my_vehicle_1", 4)]
+    );
+    assert_eq!(relations[0].jobs, to_strings(vec!["job4"]));
+}
+
+#[test]
+fn can_remove_jobs_with_multiple_time_windows_from_strict_relations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 10), (20, 30)], 1.),
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+    let relations = create_relations(&create_test_solution(), RelationType::Strict, None);
+
+    let problem = merge_relations(problem, relations);
+
+    let relations = problem.plan.relations.unwrap();
+    assert_eq!(relations[0].type_field, RelationType::Sequence);
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job2", "arrival"]));
+    assert_eq!(relations[1].type_field, RelationType::Strict);
+}
+
+#[test]
+fn can_keep_strict_relation_when_no_jobs_are_removed() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        ..create_empty_problem()
+    };
+    let relations = vec![Relation {
+        type_field: RelationType::Strict,
+        jobs: to_strings(vec!["departure", "job1"]),
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+    }];
+
+    let problem = merge_relations(problem, relations);
+
+    assert_eq!(problem.plan.relations.unwrap()[0].type_field, RelationType::Strict);
+}
+
+fn create_problem_with_breaks() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_delivery_job("job1", vec![1., 0.], vec![3., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![
+                        VehicleBreak {
+                            time: VehicleBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
+                            duration: 2.0,
+                            locations: None,
+                        },
+                        VehicleBreak {
+                            time: VehicleBreakTime::TimeWindow(vec![format_time(2.), format_time(4.)]),
+                            duration: 2.0,
+                            locations: None,
+                        },
+                    ]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_skip_breaks_when_only_second_one_is_served() {
+    let problem = create_problem_with_breaks();
+    let vehicle_ids = to_strings(vec!["my_vehicle_1"]);
+    let relations = create_relations(&create_test_solution(), RelationType::Strict, Some(vehicle_ids.as_slice()));
+
+    let problem = merge_relations(problem, relations).read_pragmatic().ok().unwrap();
+
+    let locked_types = problem
+        .locks
+        .iter()
+        .flat_map(|lock| lock.details.iter())
+        .flat_map(|detail| detail.jobs.iter())
+        .filter_map(|job| job.as_single().and_then(|single| single.dimens.get_value::<String>("type").cloned()))
+        .collect::<Vec<_>>();
+    assert!(!locked_types.is_empty());
+    assert!(!locked_types.contains(&"break".to_string()));
+}
+
+#[test]
+fn can_freeze_tour_with_break_inside_as_sequence_when_strict_is_requested() {
+    let vehicle_ids = to_strings(vec!["my_vehicle_1"]);
+    let relations = create_relations(&create_test_solution(), RelationType::Strict, Some(vehicle_ids.as_slice()));
+
+    let problem = merge_relations(create_problem_with_breaks(), relations).read_pragmatic().ok().unwrap();
+
+    let orders =
+        problem.locks.iter().flat_map(|lock| lock.details.iter()).map(|detail| &detail.order).collect::<Vec<_>>();
+    assert_eq!(orders.len(), 1);
+    assert!(matches!(orders[0], LockOrder::Sequence));
+}
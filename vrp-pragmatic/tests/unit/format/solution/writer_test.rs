@@ -23,7 +23,10 @@ fn can_create_solution() {
                 cost: 52.,
                 distance: 20,
                 duration: 22,
-                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,7 +70,10 @@ fn can_create_solution() {
                     cost: 52.,
                     distance: 20,
                     duration: 22,
-                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 0., serving: 2. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -97,7 +103,10 @@ fn can_merge_activities_in_one_stop() {
                 cost: 32.,
                 distance: 10,
                 duration: 12,
-                times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                gap: None,
+                costs: Some(CostStatistic { fixed: 10., distance: 10., driving: 10., waiting: 0., serving: 2. }),
+                objectives: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -141,7 +150,9 @@ fn can_merge_activities_in_one_stop() {
                                 }),
                                 job_tag: None
                             }
-                        ]
+                        ],
+                        latest_arrival: None,
+                        slack: None
                     },
                     create_stop_with_activity(
                         "arrival",
@@ -156,7 +167,10 @@ fn can_merge_activities_in_one_stop() {
                     cost: 32.,
                     distance: 10,
                     duration: 12,
-                    times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                    times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0, parking: 0 },
+                    gap: None,
+                    costs: Some(CostStatistic { fixed: 10., distance: 10., driving: 10., waiting: 0., serving: 2. }),
+                    objectives: None,
                 },
             }],
             unassigned: vec![],
@@ -164,3 +178,97 @@ fn can_merge_activities_in_one_stop() {
         }
     );
 }
+
+#[test]
+fn can_report_latest_arrival_and_slack() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 6)], 1.),
+                create_delivery_job("job2", vec![10., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_details(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.tours[0].stops.iter().map(|stop| (stop.latest_arrival.clone(), stop.slack)).collect::<Vec<_>>(),
+        vec![
+            (None, None),
+            (Some("1970-01-01T00:00:06Z".to_string()), Some(1)),
+            (Some("1970-01-01T00:16:29Z".to_string()), Some(978)),
+            (Some("1970-01-01T00:16:40Z".to_string()), Some(978)),
+        ]
+    );
+}
+
+#[test]
+fn can_report_cost_breakdown_and_objective_values() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![5., 0.], vec![(30, 40)], 1.),
+                create_delivery_job("job2", vec![10., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion_and_details(problem, Some(vec![matrix]));
+
+    let costs = solution.statistic.costs.clone().unwrap();
+    assert_eq!(costs, CostStatistic { fixed: 10., distance: 20., driving: 20., waiting: 14., serving: 2. });
+    assert_eq!(solution.tours[0].statistic.costs, Some(costs.clone()));
+    assert_eq!(costs.fixed + costs.distance + costs.driving + costs.waiting + costs.serving, solution.statistic.cost);
+    assert_eq!(
+        solution.statistic.objectives,
+        Some(ObjectiveStatistic {
+            primary: vec![
+                ObjectiveValue { name: "minimize-unassigned".to_string(), value: 0. },
+                ObjectiveValue { name: "minimize-tours".to_string(), value: 1. },
+            ],
+            secondary: vec![ObjectiveValue { name: "minimize-cost".to_string(), value: 66. }],
+        })
+    );
+}
+
+#[test]
+fn can_charge_parking_using_driver_and_vehicle_costs() {
+    let costs = |per_service_time: f64| vrp_core::models::problem::Costs {
+        fixed: 0.,
+        per_distance: 1.,
+        per_driving_time: 1.,
+        per_waiting_time: 1.,
+        per_service_time,
+    };
+    let actor = vrp_core::models::problem::Actor {
+        vehicle: std::sync::Arc::new(vrp_core::models::problem::Vehicle {
+            profile: 0,
+            costs: costs(2.),
+            dimens: Default::default(),
+            details: vec![],
+        }),
+        driver: std::sync::Arc::new(vrp_core::models::problem::Driver {
+            costs: costs(3.),
+            dimens: Default::default(),
+            details: vec![],
+        }),
+        detail: vrp_core::models::problem::ActorDetail {
+            start: None,
+            end: None,
+            time: vrp_core::models::common::TimeWindow::max(),
+        },
+    };
+
+    let statistic = super::get_cost_statistic(&actor, 0., 0., 0., 10., 4.);
+
+    assert_eq!(statistic.serving, 10. * 2. + 4. * (2. + 3.));
+}
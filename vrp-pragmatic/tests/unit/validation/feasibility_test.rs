@@ -0,0 +1,141 @@
+use super::*;
+use crate::helpers::*;
+
+fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
+    assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+    assert!(result.map_or("".to_string(), |err| err.action).ends_with(action));
+}
+
+fn create_test_problem(jobs: Vec<Job>, vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet { vehicles, profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_detect_job_demand_exceeding_capacity() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_demand("job1", vec![1., 0.], vec![5]),
+            create_delivery_job_with_demand("job2", vec![1., 0.], vec![11]),
+            create_delivery_job_with_demand("job3", vec![1., 0.], vec![1, 1]),
+        ],
+        vec![create_default_vehicle("vehicle")],
+    );
+
+    let result = check_w1000_job_demand_fits_capacity(&ValidationContext::new(&problem, None)).err();
+
+    assert_result("W1000", "'job2, job3'", result);
+}
+
+#[test]
+fn can_detect_job_skills_without_vehicle() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_skills("job1", vec![1., 0.], vec!["unique1".to_string()]),
+            create_delivery_job_with_skills("job2", vec![1., 0.], vec!["unique2".to_string()]),
+        ],
+        vec![VehicleType { skills: Some(vec!["unique1".to_string()]), ..create_default_vehicle("vehicle") }],
+    );
+
+    let result = check_w1001_job_skills_are_available(&ValidationContext::new(&problem, None)).err();
+
+    assert_result("W1001", "'job2'", result);
+}
+
+#[test]
+fn can_detect_job_time_windows_outside_shifts() {
+    let problem = create_test_problem(
+        vec![
+            create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 100)], 1.),
+            create_delivery_job_with_times("job2", vec![1., 0.], vec![(2000, 3000)], 1.),
+            create_delivery_job_with_times("job3", vec![1., 0.], vec![(2000, 3000), (500, 600)], 1.),
+        ],
+        vec![create_default_vehicle("vehicle")],
+    );
+
+    let result = check_w1002_job_time_windows_match_shifts(&ValidationContext::new(&problem, None)).err();
+
+    assert_result("W1002", "'job2'", result);
+}
+
+#[test]
+fn can_detect_unreachable_job_locations() {
+    let problem = create_test_problem(
+        vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+        vec![create_default_vehicle("vehicle")],
+    );
+    let matrix = Matrix { error_codes: Some(vec![0, 0, 0, 0, 0, 0, 0, 1, 0]), ..create_matrix_from_problem(&problem) };
+    let matrices = vec![matrix];
+    let ctx = ValidationContext::new(&problem, Some(&matrices));
+    let routing = Routing { coord_index: CoordIndex::new(&problem) };
+
+    let result = check_w1003_job_locations_are_reachable(&ctx, &routing).err();
+
+    assert_result("W1003", "'job2'", result);
+}
+
+#[test]
+fn can_detect_relation_jobs_which_cannot_be_served() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job_with_skills("job2", vec![1., 0.], vec!["unique".to_string()]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Sequence,
+                jobs: vec!["departure".to_string(), "job1".to_string(), "job2".to_string()],
+                vehicle_id: "vehicle1_1".to_string(),
+                shift_index: None,
+            }]),
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle1"),
+                VehicleType { skills: Some(vec!["unique".to_string()]), ..create_default_vehicle("vehicle2") },
+            ],
+            profiles: create_default_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let routing = Routing { coord_index: CoordIndex::new(&problem) };
+
+    assert!(check_w1001_job_skills_are_available(&ctx).is_ok());
+
+    let result = check_w1004_relation_jobs_can_be_served(&ctx, &routing).err();
+
+    assert_result("W1004", "'job2'", result);
+}
+
+#[test]
+fn can_analyze_feasible_problem_without_warnings() {
+    let problem = create_test_problem(
+        vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+        vec![create_default_vehicle("vehicle")],
+    );
+
+    let warnings = ValidationContext::new(&problem, None).analyze();
+
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn can_detect_times_with_different_offsets() {
+    let mut problem = create_test_problem(
+        vec![
+            create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 10)], 0.),
+            create_delivery_job("job2", vec![2., 0.]),
+        ],
+        vec![create_default_vehicle("vehicle")],
+    );
+    problem.fleet.vehicles[0].shifts[0].start.time = "1970-01-01T02:00:00+02:00".to_string();
+    let ctx = ValidationContext::new(&problem, None);
+
+    let result = check_w1005_times_have_same_offset(&ctx).err();
+
+    assert_result("W1005", "'+00:00, +02:00'", result);
+}
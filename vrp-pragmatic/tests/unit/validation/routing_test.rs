@@ -1,5 +1,6 @@
 use super::*;
-use crate::helpers::create_empty_problem;
+use crate::format::Location;
+use crate::helpers::*;
 
 #[test]
 fn can_detect_duplicates() {
@@ -29,3 +30,174 @@ fn can_detect_empty_profiles() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1501".to_string()));
 }
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: None },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn assert_matrix_result(expected: Option<(&str, &str)>, result: Result<(), FormatError>) {
+    let result = result.err().map(|err| (err.code, err.action));
+
+    match (expected, result) {
+        (Some((code, details)), Some((actual_code, action))) => {
+            assert_eq!(actual_code, code);
+            assert!(action.ends_with(details), "unexpected action: {}", action);
+        }
+        (None, None) => {}
+        (expected, result) => panic!("expected: {:?}, actual: {:?}", expected, result),
+    }
+}
+
+parameterized_test! {can_detect_wrong_matrix_size, (travel_times, distances, expected), {
+    can_detect_wrong_matrix_size_impl(travel_times, distances, expected);
+}}
+
+can_detect_wrong_matrix_size! {
+    case01: (vec![0, 1, 1, 0], vec![0, 1, 1, 0], None),
+    case02: (vec![0, 1, 1], vec![0, 1, 1, 0], Some(("E1502", "'car'"))),
+    case03: (vec![0, 1, 1, 0], vec![0, 1, 1, 0, 1], Some(("E1502", "'car'"))),
+}
+
+fn can_detect_wrong_matrix_size_impl(travel_times: Vec<i64>, distances: Vec<i64>, expected: Option<(&str, &str)>) {
+    let problem = create_test_problem();
+    let matrices = vec![Matrix { travel_times, distances, ..create_matrix_from_problem(&problem) }];
+
+    let result = check_e1502_matrix_size(&ValidationContext::new(&problem, Some(&matrices)), 2);
+
+    assert_matrix_result(expected, result);
+}
+
+parameterized_test! {can_detect_negative_matrix_values, (travel_times, distances, expected), {
+    can_detect_negative_matrix_values_impl(travel_times, distances, expected);
+}}
+
+can_detect_negative_matrix_values! {
+    case01: (vec![0, 1, 1, 0], vec![0, 1, 1, 0], None),
+    case02: (vec![0, -1, 1, 0], vec![0, 1, 1, 0], Some(("E1503", "'car [1]'"))),
+    case03: (vec![0, -1, 1, 0], vec![0, 1, -1, 0], Some(("E1503", "'car [1, 2]'"))),
+    case04: (vec![-1; 16], vec![0; 16], Some(("E1503", "'car [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, ... 16 in total]'"))),
+}
+
+fn can_detect_negative_matrix_values_impl(travel_times: Vec<i64>, distances: Vec<i64>, expected: Option<(&str, &str)>) {
+    let problem = create_test_problem();
+    let matrices = vec![Matrix { travel_times, distances, ..create_matrix_from_problem(&problem) }];
+
+    let result = check_e1503_matrix_negative_values(&ValidationContext::new(&problem, Some(&matrices)));
+
+    assert_matrix_result(expected, result);
+}
+
+parameterized_test! {can_detect_wrong_error_codes_size, (error_codes, expected), {
+    can_detect_wrong_error_codes_size_impl(error_codes, expected);
+}}
+
+can_detect_wrong_error_codes_size! {
+    case01: (None, None),
+    case02: (Some(vec![0, 0, 0, 0]), None),
+    case03: (Some(vec![0, 0, 0]), Some(("E1504", "'car'"))),
+}
+
+fn can_detect_wrong_error_codes_size_impl(error_codes: Option<Vec<i64>>, expected: Option<(&str, &str)>) {
+    let problem = create_test_problem();
+    let matrices = vec![Matrix { error_codes, ..create_matrix_from_problem(&problem) }];
+
+    let result = check_e1504_matrix_error_codes_size(&ValidationContext::new(&problem, Some(&matrices)), 2);
+
+    assert_matrix_result(expected, result);
+}
+
+parameterized_test! {can_detect_non_zero_diagonal, (travel_times, distances, expected), {
+    can_detect_non_zero_diagonal_impl(travel_times, distances, expected);
+}}
+
+can_detect_non_zero_diagonal! {
+    case01: (vec![0, 1, 1, 0], vec![0, 1, 1, 0], None),
+    case02: (vec![1, 1, 1, 0], vec![0, 1, 1, 0], Some(("E1505", "'car [0]'"))),
+    case03: (vec![0, 1, 1, 0], vec![1, 1, 1, 1], Some(("E1505", "'car [0, 3]'"))),
+    case04: (vec![1, 1, 1], vec![1, 1, 1], None),
+}
+
+fn can_detect_non_zero_diagonal_impl(travel_times: Vec<i64>, distances: Vec<i64>, expected: Option<(&str, &str)>) {
+    let problem = create_test_problem();
+    let matrices = vec![Matrix { travel_times, distances, ..create_matrix_from_problem(&problem) }];
+
+    let result = check_e1505_matrix_non_zero_diagonal(&ValidationContext::new(&problem, Some(&matrices)), 2);
+
+    assert_matrix_result(expected, result);
+}
+
+#[test]
+fn can_validate_matrices_with_problem_size() {
+    let problem = create_test_problem();
+    let matrices = vec![Matrix { travel_times: vec![0, 1, 1], ..create_matrix_from_problem(&problem) }];
+
+    let result = validate_matrices(&ValidationContext::new(&problem, Some(&matrices)));
+
+    assert_eq!(
+        result.err().map(|errors| errors.into_iter().map(|err| err.code).collect()),
+        Some(vec!["E1502".to_string()])
+    );
+}
+
+fn create_index_problem(job_location: Location) -> Problem {
+    let mut problem = create_test_problem();
+    problem.plan.jobs[0].deliveries.as_mut().unwrap()[0].places[0].location = job_location;
+    problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.shifts.iter_mut()).for_each(|shift| {
+        shift.start.location = Location::new_reference(0);
+        shift.end.iter_mut().for_each(|end| end.location = Location::new_reference(0));
+    });
+
+    problem
+}
+
+parameterized_test! {can_detect_mixed_location_types, (job_location, expected), {
+    can_detect_mixed_location_types_impl(job_location, expected);
+}}
+
+can_detect_mixed_location_types! {
+    case01: (Location::new_reference(1), None),
+    case02: (Location::new(1., 0.), Some("E1506")),
+}
+
+fn can_detect_mixed_location_types_impl(job_location: Location, expected: Option<&str>) {
+    let problem = create_index_problem(job_location);
+
+    let result = check_e1506_mixed_location_types(&CoordIndex::new(&problem));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+#[test]
+fn can_detect_index_locations_without_matrices() {
+    let problem = create_index_problem(Location::new_reference(1));
+
+    let result = validate_matrices(&ValidationContext::new(&problem, None));
+
+    assert_eq!(
+        result.err().map(|errors| errors.into_iter().map(|err| err.code).collect()),
+        Some(vec!["E1507".to_string()])
+    );
+}
+
+parameterized_test! {can_get_matrix_size_for_index_locations, (job_index, matrix_size, expected), {
+    can_get_matrix_size_for_index_locations_impl(job_index, matrix_size, expected);
+}}
+
+can_get_matrix_size_for_index_locations! {
+    case01: (1, 2, 2),
+    case02: (1, 4, 4),
+    case03: (3, 2, 4),
+}
+
+fn can_get_matrix_size_for_index_locations_impl(job_index: usize, matrix_size: usize, expected: usize) {
+    let problem = create_index_problem(Location::new_reference(job_index));
+    let matrices = vec![create_matrix(vec![0; matrix_size * matrix_size])];
+
+    let size = get_matrix_size(&ValidationContext::new(&problem, Some(&matrices)), &CoordIndex::new(&problem));
+
+    assert_eq!(size, expected);
+}
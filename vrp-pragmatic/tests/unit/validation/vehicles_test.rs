@@ -3,7 +3,7 @@ use crate::format::Location;
 use crate::helpers::*;
 
 fn coord(lat: f64, lng: f64) -> Location {
-    Location { lat, lng }
+    Location::Coordinate { lat, lng }
 }
 
 parameterized_test! {can_detect_invalid_area, (allowed_areas, expected), {
@@ -38,3 +38,144 @@ fn can_detect_invalid_area_impl(allowed_areas: Option<Vec<Vec<Location>>>, expec
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1305".to_string()));
 }
+
+parameterized_test! {can_detect_negative_costs, (costs, expected), {
+    can_detect_negative_costs_impl(costs, expected);
+}}
+
+can_detect_negative_costs! {
+    case01: ((Some(10.), 1., 1., None, None), None),
+    case02: ((None, 1., 1., Some(0.), Some(2.)), None),
+
+    case03: ((Some(-1.), 1., 1., None, None), Some(())),
+    case04: ((None, -1., 1., None, None), Some(())),
+    case05: ((None, 1., -1., None, None), Some(())),
+    case06: ((None, 1., 1., Some(-1.), None), Some(())),
+    case07: ((None, 1., 1., None, Some(-1.)), Some(())),
+}
+
+fn can_detect_negative_costs_impl(costs: (Option<f64>, f64, f64, Option<f64>, Option<f64>), expected: Option<()>) {
+    let (fixed, distance, time, waiting, service) = costs;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { fixed, distance, time, waiting, service },
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1306_vehicle_costs_are_not_negative(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1306".to_string()));
+}
+
+parameterized_test! {can_detect_negative_parking, (parking, expected), {
+    can_detect_negative_parking_impl(parking, expected);
+}}
+
+can_detect_negative_parking! {
+    case01: (None, None),
+    case02: (Some(0.), None),
+    case03: (Some(10.), None),
+    case04: (Some(-1.), Some(())),
+}
+
+fn can_detect_negative_parking_impl(parking: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![VehicleType { parking, ..create_default_vehicle_type() }], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_parking_is_not_negative(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1307".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_service_factor, (service_factor, expected), {
+    can_detect_invalid_service_factor_impl(service_factor, expected);
+}}
+
+can_detect_invalid_service_factor! {
+    case01: (None, None),
+    case02: (Some(0.5), None),
+    case03: (Some(3.), None),
+    case04: (Some(0.), Some(())),
+    case05: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_service_factor_impl(service_factor: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { service_factor, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_vehicle_service_factor_is_positive(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1308".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_speed_factor, (speed_factor, expected), {
+    can_detect_invalid_speed_factor_impl(speed_factor, expected);
+}}
+
+can_detect_invalid_speed_factor! {
+    case01: (None, None),
+    case02: (Some(0.5), None),
+    case03: (Some(2.), None),
+    case04: (Some(0.), Some(())),
+    case05: (Some(-1.), Some(())),
+}
+
+fn can_detect_invalid_speed_factor_impl(speed_factor: Option<f64>, expected: Option<()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { speed_factor, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_speed_factor_is_positive(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1309".to_string()));
+}
+
+parameterized_test! {can_detect_area_with_index_locations, (job_location, area_location, expected), {
+    can_detect_area_with_index_locations_impl(job_location, area_location, expected);
+}}
+
+can_detect_area_with_index_locations! {
+    case01: (coord(1., 0.), coord(0., 0.), None),
+    case02: (Location::new_reference(0), coord(0., 0.), Some(())),
+    case03: (coord(1., 0.), Location::new_reference(0), Some(())),
+}
+
+fn can_detect_area_with_index_locations_impl(job_location: Location, area_location: Location, expected: Option<()>) {
+    let mut job = create_delivery_job("job1", vec![1., 0.]);
+    job.deliveries.as_mut().unwrap()[0].places[0].location = job_location;
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: Some(vec![vec![area_location, coord(0., 1.), coord(1., 1.)]]),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_limit_area_with_index_locations(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1310".to_string()));
+}